@@ -225,6 +225,24 @@ pub fn component_impl(
         ));
     }
 
+    // Components that return a `Result` report the error to the nearest `ErrorBoundary` and
+    // render nothing in its place.
+    let generic_node_ident = &generic_node_ty.ident;
+    let body = if returns_result(&return_type) {
+        quote! {
+            let __result: #return_type = (move || #block)();
+            match __result {
+                ::std::result::Result::Ok(view) => view,
+                ::std::result::Result::Err(err) => {
+                    ::sycamore::error_boundary::throw_error(err);
+                    ::sycamore::view::View::empty()
+                }
+            }
+        }
+    } else {
+        quote! { #block }
+    };
+
     let quoted = quote! {
         #(#attrs)*
         #vis struct #component_name#generics {
@@ -232,18 +250,29 @@ pub fn component_impl(
             _marker: ::std::marker::PhantomData<(#phantom_generics)>,
         }
 
-        impl#impl_generics ::sycamore::component::Component::<#generic_node_ty> for #component_name#ty_generics
+        impl#impl_generics ::sycamore::component::Component::<#generic_node_ty>
+            for #component_name#ty_generics
             #where_clause
         {
             #[cfg(debug_assertions)]
             const NAME: &'static ::std::primitive::str = #component_name_str;
             type Props = #prop_ty;
 
-            fn __create_component(#arg) -> #return_type{
-                #block
+            fn __create_component(#arg) -> ::sycamore::view::View<#generic_node_ident> {
+                #body
             }
         }
     };
 
     Ok(quoted)
 }
+
+/// Returns `true` if the type is a `Result`, i.e. the last segment of its path is `Result`.
+fn returns_result(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => {
+            matches!(path.path.segments.last(), Some(segment) if segment.ident == "Result")
+        }
+        _ => false,
+    }
+}
@@ -2,6 +2,7 @@ use proc_macro::TokenStream;
 use syn::parse_macro_input;
 
 mod component;
+mod slots;
 mod view;
 
 /// A macro for ergonomically creating complex UI structures.
@@ -49,3 +50,20 @@ pub fn component(attr: TokenStream, component: TokenStream) -> TokenStream {
         .unwrap_or_else(|err| err.to_compile_error())
         .into()
 }
+
+/// A derive macro for props with named slots.
+///
+/// Fields marked with `#[slot]` can be filled in by the caller with `Slot(name="...") { ... }` in
+/// the children of the component. The props are created before the slots are filled in, so the slot
+/// fields also need an initial value, usually an empty `View` from a `Default` implementation.
+///
+/// To learn more about slots, see the chapter on
+/// [components](https://sycamore-rs.netlify.app/docs/basics/components) in the Sycamore Book.
+#[proc_macro_derive(Slots, attributes(slot))]
+pub fn slots(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+
+    slots::slots_impl(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,60 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Result};
+
+pub fn slots_impl(input: DeriveInput) -> Result<TokenStream> {
+    let DeriveInput {
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let fields = match data {
+        Data::Struct(data) => match data.fields {
+            Fields::Named(fields) => fields.named,
+            fields => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "`Slots` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`Slots` can only be derived for structs",
+            ))
+        }
+    };
+
+    let setters = fields
+        .iter()
+        .filter(|field| field.attrs.iter().any(|attr| attr.path.is_ident("slot")))
+        .map(|field| {
+            let name = field.ident.as_ref().unwrap();
+            let ty = &field.ty;
+            let setter = format_ident!("__slot_{}", name);
+            quote! {
+                #[doc(hidden)]
+                pub fn #setter(&mut self, view: #ty) {
+                    self.#name = view;
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if setters.is_empty() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "expected at least one field with a `#[slot]` attribute",
+        ));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl#impl_generics #ident#ty_generics #where_clause {
+            #(#setters)*
+        }
+    })
+}
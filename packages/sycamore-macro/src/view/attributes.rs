@@ -5,14 +5,16 @@ use once_cell::sync::Lazy;
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::ext::IdentExt;
+use syn::parse::discouraged::Speculative;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Paren;
-use syn::{parenthesized, Expr, ExprLit, Ident, Lit, Result, Token};
+use syn::{parenthesized, Expr, ExprLit, Ident, Lit, LitStr, Result, Token};
 
 static BOOLEAN_ATTRIBUTES_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     vec![
+        "allowfullscreen",
         "async",
         "autofocus",
         "autoplay",
@@ -26,19 +28,24 @@ static BOOLEAN_ATTRIBUTES_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
         "defer",
         "disabled",
         "formNoValidate",
+        "formnovalidate",
         "frameborder",
         "hidden",
         "indeterminate",
+        "inert",
         "ismap",
+        "itemscope",
         "loop",
         "multiple",
         "muted",
         "nohref",
+        "nomodule",
         "noresize",
         "noshade",
         "novalidate",
         "nowrap",
         "open",
+        "playsinline",
         "readonly",
         "required",
         "reversed",
@@ -66,11 +73,23 @@ pub enum AttributeType {
     Bool { name: AttributeName },
     /// Syntax: `dangerously_set_inner_html`.
     DangerouslySetInnerHtml,
+    /// Syntax: `focus:auto`. Unlike the HTML `autofocus` attribute, this also works for nodes
+    /// that are inserted dynamically.
+    AutoFocus,
+    /// Syntax: `select_on_focus`.
+    SelectOnFocus,
+    /// Syntax: `classes`.
+    Classes,
+    /// Syntax: `class=("<base>", <name> => <cond>, ...)`. The base classes are stored in the
+    /// attribute's `expr` and the toggles in `class_toggles`.
+    ClassToggles,
     /// Syntax: `on:<event>`.
     Event { event: String },
     /// Syntax: `bind:<prop>`.
     Bind { prop: String },
-    /// Syntax: `ref`.
+    /// Syntax: `style:<property>`. The property can be a custom property (e.g. `style:--color`).
+    Style { property: String },
+    /// Syntax: `ref`. The value is either a `NodeRef` or a closure that is called with the node.
     Ref,
 }
 
@@ -83,11 +102,16 @@ impl Parse for AttributeType {
             Ok(Self::Ref)
         } else if ident_str == "dangerously_set_inner_html" {
             Ok(Self::DangerouslySetInnerHtml)
+        } else if ident_str == "select_on_focus" {
+            Ok(Self::SelectOnFocus)
+        } else if ident_str == "classes" {
+            Ok(Self::Classes)
         } else if input.peek(Token![:]) {
             let _colon: Token![:] = input.parse()?;
             match ident_str.as_str() {
                 "on" => {
-                    let event = input.call(Ident::parse_any)?;
+                    // Custom events can contain dashes, e.g. `on:item-selected`.
+                    let event: AttributeName = input.parse()?;
                     Ok(Self::Event {
                         event: event.to_string(),
                     })
@@ -98,6 +122,24 @@ impl Parse for AttributeType {
                         prop: prop.to_string(),
                     })
                 }
+                "focus" => {
+                    let name: Ident = input.parse()?;
+                    if name != "auto" {
+                        return Err(syn::Error::new_spanned(name, "expected `focus:auto`"));
+                    }
+                    Ok(Self::AutoFocus)
+                }
+                "style" => {
+                    // `--` is parsed as two `-` tokens.
+                    let mut property = String::new();
+                    while input.peek(Token![-]) {
+                        input.parse::<Token![-]>()?;
+                        property.push('-');
+                    }
+                    let name: AttributeName = input.parse()?;
+                    property.push_str(&name.to_string());
+                    Ok(Self::Style { property })
+                }
                 _ => Err(syn::Error::new_spanned(
                     ident.tag,
                     format!("unknown directive `{}`", ident_str),
@@ -115,18 +157,88 @@ pub struct Attribute {
     pub ty: AttributeType,
     pub equals_token: Token![=],
     pub expr: Expr,
+    /// Only used by [`AttributeType::ClassToggles`].
+    pub class_toggles: Vec<ClassToggle>,
 }
 
 impl Parse for Attribute {
     fn parse(input: ParseStream) -> Result<Self> {
+        let ty: AttributeType = input.parse()?;
+        let equals_token = input.parse()?;
+
+        if matches!(&ty, AttributeType::Str { name } if name.to_string() == "class")
+            && input.peek(Paren)
+        {
+            // `class=(...)` is only treated as a list of class toggles if it parses as one.
+            // Otherwise, it is a parenthesized expression.
+            let fork = input.fork();
+            if let Ok((base, class_toggles)) = fork.call(parse_class_toggles) {
+                input.advance_to(&fork);
+                return Ok(Self {
+                    ty: AttributeType::ClassToggles,
+                    equals_token,
+                    expr: Expr::Lit(ExprLit {
+                        attrs: Vec::new(),
+                        lit: Lit::Str(base),
+                    }),
+                    class_toggles,
+                });
+            }
+        }
+
         Ok(Self {
-            ty: input.parse()?,
-            equals_token: input.parse()?,
+            ty,
+            equals_token,
             expr: input.parse()?,
+            class_toggles: Vec::new(),
+        })
+    }
+}
+
+/// A class that is only added while `cond` is `true`.
+///
+/// Syntax: `<name> => <cond>`, where `name` is either a string literal or an attribute name.
+pub struct ClassToggle {
+    pub name: String,
+    pub cond: Expr,
+}
+
+impl Parse for ClassToggle {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = if input.peek(LitStr) {
+            input.parse::<LitStr>()?.value()
+        } else {
+            input.parse::<AttributeName>()?.to_string()
+        };
+        input.parse::<Token![=>]>()?;
+        Ok(Self {
+            name,
+            cond: input.parse()?,
         })
     }
 }
 
+/// Parses `("<base>", <name> => <cond>, ...)`.
+fn parse_class_toggles(input: ParseStream) -> Result<(LitStr, Vec<ClassToggle>)> {
+    let content;
+    parenthesized!(content in input);
+
+    let base: LitStr = content.parse()?;
+    let mut toggles = Vec::new();
+    while !content.is_empty() {
+        content.parse::<Token![,]>()?;
+        if content.is_empty() {
+            break;
+        }
+        toggles.push(content.parse()?);
+    }
+
+    if toggles.is_empty() {
+        return Err(content.error("expected at least one class toggle"));
+    }
+    Ok((base, toggles))
+}
+
 impl ToTokens for Attribute {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let expr = &self.expr;
@@ -240,6 +352,70 @@ impl ToTokens for Attribute {
                     });
                 };
             }
+            AttributeType::AutoFocus => {
+                tokens.extend(quote_spanned! { expr_span=>
+                    if #expr {
+                        ::sycamore::generic_node::GenericNode::focus(&__el);
+                    }
+                });
+            }
+            AttributeType::SelectOnFocus => {
+                // Calls `select()` on the element that gained focus. `select` is looked up
+                // dynamically so that this works for both `<input>` and `<textarea>`.
+                tokens.extend(quote_spanned! { expr_span=>
+                    if #expr {
+                        ::sycamore::generic_node::GenericNode::event(
+                            &__el,
+                            "focus",
+                            ::std::boxed::Box::new(|event: ::sycamore::rt::Event| {
+                                if let ::std::option::Option::Some(target) = event.target() {
+                                    let select = ::sycamore::rt::Reflect::get(
+                                        &target,
+                                        &::sycamore::rt::JsValue::from_str("select"),
+                                    )
+                                    .unwrap();
+                                    if let ::std::option::Option::Some(select) =
+                                        ::sycamore::rt::JsCast::dyn_ref::<::sycamore::rt::Function>(
+                                            &select,
+                                        )
+                                    {
+                                        let _ = select.call0(&target);
+                                    }
+                                }
+                            }),
+                        );
+                    }
+                });
+            }
+            AttributeType::ClassToggles => {
+                // The base classes are static. Each toggle gets its own effect so that only the
+                // classes whose condition changed are touched.
+                tokens.extend(quote_spanned! { expr_span=>
+                    ::sycamore::generic_node::GenericNode::set_class_name(&__el, #expr);
+                });
+                for ClassToggle { name, cond } in &self.class_toggles {
+                    tokens.extend(quote_spanned! { cond.span()=>
+                        ::sycamore::reactive::create_effect({
+                            let __el = ::std::clone::Clone::clone(&__el);
+                            move || {
+                                if #cond {
+                                    ::sycamore::generic_node::GenericNode::add_class(&__el, #name);
+                                } else {
+                                    ::sycamore::generic_node::GenericNode::remove_class(
+                                        &__el,
+                                        #name,
+                                    );
+                                }
+                            }
+                        });
+                    });
+                }
+            }
+            AttributeType::Classes => {
+                tokens.extend(quote_spanned! { expr_span=>
+                    ::sycamore::utils::render::bind_classes(&__el, &#expr);
+                });
+            }
             AttributeType::Event { event } => {
                 // TODO: Should events be reactive?
                 tokens.extend(quote_spanned! { expr_span=>
@@ -250,6 +426,30 @@ impl ToTokens for Attribute {
                     );
                 });
             }
+            AttributeType::Style { property } => {
+                let quoted_set_style_property = quote! {
+                    ::sycamore::generic_node::GenericNode::set_style_property(
+                        &__el,
+                        #property,
+                        &::std::string::ToString::to_string(&#expr),
+                    );
+                };
+
+                if is_dynamic {
+                    tokens.extend(quote_spanned! { expr_span=>
+                        ::sycamore::reactive::create_effect({
+                            let __el = ::std::clone::Clone::clone(&__el);
+                            move || {
+                                #quoted_set_style_property
+                            }
+                        });
+                    });
+                } else {
+                    tokens.extend(quote_spanned! { expr_span=>
+                        #quoted_set_style_property
+                    });
+                };
+            }
             AttributeType::Bind { prop } => {
                 #[derive(Clone, Copy)]
                 enum JsPropertyType {
@@ -259,6 +459,7 @@ impl ToTokens for Attribute {
 
                 let (event_name, property_ty) = match prop.as_str() {
                     "value" => ("input", JsPropertyType::String),
+                    "textContent" => ("input", JsPropertyType::String),
                     "checked" => ("change", JsPropertyType::Bool),
                     _ => {
                         tokens.extend(
@@ -306,38 +507,166 @@ impl ToTokens for Attribute {
                     },
                 };
 
+                // Text inputs can be edited with an IME (Input Method Editor). The `input` event
+                // is fired for every intermediate value while composing, so the signal is only
+                // updated once the composition has ended. Depending on the browser, the final
+                // value is either reported by an `input` event before `compositionend` or by one
+                // after it (Firefox), so the value is only written if it changed.
+                let quoted_set_signal = if prop == "value" {
+                    quote! {
+                        let __composing = ::std::rc::Rc::new(::std::cell::Cell::new(false));
+                        let __set_if_changed = {
+                            let signal = ::std::clone::Clone::clone(&signal);
+                            move |value: ::std::string::String| {
+                                if *signal.get_untracked() != value {
+                                    signal.set(value);
+                                }
+                            }
+                        };
+
+                        ::sycamore::generic_node::GenericNode::event(
+                            &__el,
+                            "compositionstart",
+                            ::std::boxed::Box::new({
+                                let __composing = ::std::rc::Rc::clone(&__composing);
+                                move |_: ::sycamore::rt::Event| __composing.set(true)
+                            }),
+                        );
+
+                        ::sycamore::generic_node::GenericNode::event(
+                            &__el,
+                            "compositionend",
+                            ::std::boxed::Box::new({
+                                let __composing = ::std::rc::Rc::clone(&__composing);
+                                let __set_if_changed = ::std::clone::Clone::clone(&__set_if_changed);
+                                move |event: ::sycamore::rt::Event| {
+                                    __composing.set(false);
+                                    __set_if_changed(#convert_from_jsvalue_fn);
+                                }
+                            }),
+                        );
+
+                        ::sycamore::generic_node::GenericNode::event(
+                            &__el,
+                            #event_name,
+                            ::std::boxed::Box::new(move |event: ::sycamore::rt::Event| {
+                                let __is_composing = ::sycamore::rt::Reflect::get(
+                                    &event,
+                                    &::std::convert::Into::<::sycamore::rt::JsValue>::into(
+                                        "isComposing",
+                                    ),
+                                )
+                                .ok()
+                                .and_then(|is_composing| is_composing.as_bool())
+                                .unwrap_or(false);
+                                if !__composing.get() && !__is_composing {
+                                    __set_if_changed(#convert_from_jsvalue_fn);
+                                }
+                            }),
+                        )
+                    }
+                } else if prop == "textContent" {
+                    // The text that was read from the element is remembered so that the effect
+                    // below does not write it back.
+                    quote! {
+                        ::sycamore::generic_node::GenericNode::event(
+                            &__el,
+                            #event_name,
+                            ::std::boxed::Box::new(move |event: ::sycamore::rt::Event| {
+                                let text: ::std::string::String = #convert_from_jsvalue_fn;
+                                *__dom_text.borrow_mut() =
+                                    ::std::option::Option::Some(::std::clone::Clone::clone(&text));
+                                signal.set(text);
+                            }),
+                        )
+                    }
+                } else {
+                    quote! {
+                        ::sycamore::generic_node::GenericNode::event(
+                            &__el,
+                            #event_name,
+                            ::std::boxed::Box::new(move |event: ::sycamore::rt::Event| {
+                                signal.set(#convert_from_jsvalue_fn);
+                            }),
+                        )
+                    }
+                };
+
+                // Setting `textContent` replaces all the children of the element, which moves the
+                // cursor of a `contenteditable` element to the start. The property is therefore
+                // only set when the signal holds a different text than the element.
+                let quoted_set_property = if prop == "textContent" {
+                    quote! {
+                        let __text = signal.get();
+                        if __dom_text.borrow().as_deref() != ::std::option::Option::Some(__text.as_str()) {
+                            ::sycamore::generic_node::GenericNode::set_property(
+                                &__el,
+                                #prop,
+                                &::sycamore::rt::JsValue::from_str(&__text),
+                            );
+                            *__dom_text.borrow_mut() =
+                                ::std::option::Option::Some(::std::string::ToString::to_string(&__text));
+                        }
+                    }
+                } else {
+                    quote! {
+                        ::sycamore::generic_node::GenericNode::set_property(
+                            &__el,
+                            #prop,
+                            &#convert_into_jsvalue_fn,
+                        );
+                    }
+                };
+                let quoted_dom_text = if prop == "textContent" {
+                    quote! {
+                        let __dom_text: ::std::rc::Rc<
+                            ::std::cell::RefCell<::std::option::Option<::std::string::String>>,
+                        > = ::std::default::Default::default();
+                    }
+                } else {
+                    quote! {}
+                };
+                let quoted_clone_dom_text = if prop == "textContent" {
+                    quote! { let __dom_text = ::std::rc::Rc::clone(&__dom_text); }
+                } else {
+                    quote! {}
+                };
+
                 tokens.extend(quote_spanned! { expr_span=> {
                     let signal: ::sycamore::reactive::Signal<#value_ty> = #expr;
+                    #quoted_dom_text
 
                     #[cfg(target_arch = "wasm32")]
                     ::sycamore::reactive::create_effect({
                         let signal = ::std::clone::Clone::clone(&signal);
                         let __el = ::std::clone::Clone::clone(&__el);
+                        #quoted_clone_dom_text
                         move || {
-                            ::sycamore::generic_node::GenericNode::set_property(
-                                &__el,
-                                #prop,
-                                &#convert_into_jsvalue_fn,
-                            );
+                            #quoted_set_property
                         }
                     });
 
-                    ::sycamore::generic_node::GenericNode::event(
-                        &__el,
-                        #event_name,
-                        ::std::boxed::Box::new(move |event: ::sycamore::rt::Event| {
-                            signal.set(#convert_from_jsvalue_fn);
-                        }),
-                    )
+                    #quoted_set_signal
                 }});
             }
             AttributeType::Ref => {
-                tokens.extend(quote_spanned! { expr_span=>{
-                    ::sycamore::noderef::NodeRef::set(
-                        &#expr,
-                        ::std::clone::Clone::clone(&__el),
-                    );
-                }});
+                if let Expr::Closure(_) = expr {
+                    // Callback ref. The helper function provides the type of the closure parameter
+                    // so that it does not need to be annotated.
+                    tokens.extend(quote_spanned! { expr_span=>{
+                        fn __call_ref<__G>(node: __G, f: impl ::std::ops::FnOnce(__G)) {
+                            f(node);
+                        }
+                        __call_ref(::std::clone::Clone::clone(&__el), #expr);
+                    }});
+                } else {
+                    tokens.extend(quote_spanned! { expr_span=>{
+                        ::sycamore::noderef::NodeRef::set(
+                            &#expr,
+                            ::std::clone::Clone::clone(&__el),
+                        );
+                    }});
+                }
             }
         }
     }
@@ -5,30 +5,167 @@ use quote::{quote, quote_spanned, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::token::{Comma, Paren};
-use syn::{parenthesized, parse_quote, Expr, GenericArgument, Path, Result};
+use syn::token::{Brace, Comma, Paren};
+use syn::{
+    braced, parenthesized, parse_quote, Expr, GenericArgument, Ident, LitStr, Path, Result, Token,
+};
+
+use super::HtmlRoot;
 
 /// Components are identical to function calls.
+///
+/// A component can be followed by a block of named slots (e.g.
+/// `Dialog(..) { Slot(name="header") { ... } }`), which are assigned to the props after they have
+/// been created.
 pub struct Component {
     pub path: Path,
     pub paren: Paren,
     pub args: Punctuated<Expr, Comma>,
+    pub spread: Option<PropsSpread>,
+    pub slots: Vec<Slot>,
+}
+
+/// Content for a field of the props marked with `#[slot]` (e.g. `Slot(name="header") { ... }`).
+pub struct Slot {
+    pub name: LitStr,
+    pub body: HtmlRoot,
+}
+
+impl Parse for Slot {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident != "Slot" {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "expected `Slot(name=\"...\")`; the children of a component must be named slots",
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        let key: Ident = content.parse()?;
+        if key != "name" {
+            return Err(syn::Error::new_spanned(key, "expected `name`"));
+        }
+        content.parse::<Token![=]>()?;
+        let name: LitStr = content.parse()?;
+        if syn::parse_str::<Ident>(&name.value()).is_err() {
+            return Err(syn::Error::new_spanned(
+                name,
+                "the name of a slot must be a valid field name",
+            ));
+        }
+
+        let body;
+        braced!(body in input);
+
+        Ok(Self {
+            name,
+            body: body.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Slot {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let Slot { name, body, .. } = self;
+        // Calls the setter generated by `#[derive(Slots)]` so that only slot fields can be set.
+        let setter = Ident::new(&format!("__slot_{}", name.value()), name.span());
+        tokens.extend(quote_spanned! { name.span()=>
+            __props.#setter(#body);
+        });
+    }
+}
+
+/// Props that are spread onto a component (e.g. `Child(label: value, ..props)`).
+///
+/// The base props are moved and the explicitly specified fields are assigned afterwards, so
+/// explicit fields override the fields of the base props.
+pub struct PropsSpread {
+    pub fields: Punctuated<PropsField, Comma>,
+    pub base: Expr,
+}
+
+/// An explicitly specified field in a [`PropsSpread`].
+pub struct PropsField {
+    pub name: Ident,
+    pub value: Expr,
+}
+
+impl Parse for PropsField {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        Ok(Self {
+            name,
+            value: input.parse()?,
+        })
+    }
+}
+
+impl Parse for PropsSpread {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut fields = Punctuated::new();
+        while !input.peek(Token![..]) {
+            if input.is_empty() {
+                return Err(input.error("expected `..` followed by the props to spread"));
+            }
+            fields.push_value(input.parse()?);
+            if !input.is_empty() {
+                fields.push_punct(input.parse()?);
+            }
+        }
+
+        input.parse::<Token![..]>()?;
+        Ok(Self {
+            fields,
+            base: input.parse()?,
+        })
+    }
 }
 
 impl Parse for Component {
     fn parse(input: ParseStream) -> Result<Self> {
+        let path = input.parse()?;
         let content;
+        let paren = parenthesized!(content in input);
+
+        let is_spread = content.peek(Token![..])
+            || (content.peek(Ident) && content.peek2(Token![:]) && !content.peek2(Token![::]));
+        let (args, spread) = if is_spread {
+            (Punctuated::new(), Some(content.parse()?))
+        } else {
+            (content.parse_terminated(Expr::parse)?, None)
+        };
+
+        let mut slots = Vec::new();
+        if input.peek(Brace) {
+            let content;
+            braced!(content in input);
+            while !content.is_empty() {
+                slots.push(content.parse()?);
+            }
+        }
+
         Ok(Self {
-            path: input.parse()?,
-            paren: parenthesized!(content in input),
-            args: content.parse_terminated(Expr::parse)?,
+            path,
+            paren,
+            args,
+            spread,
+            slots,
         })
     }
 }
 
 impl ToTokens for Component {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Component { path, paren, args } = self;
+        let Component {
+            path,
+            paren,
+            args,
+            spread,
+            slots,
+        } = self;
         let mut path = path.clone();
 
         let generic_arg: GenericArgument = parse_quote! { _ };
@@ -57,7 +194,34 @@ impl ToTokens for Component {
             syn::PathArguments::Parenthesized(_) => unreachable!(),
         };
 
-        let quoted = if args.empty_or_trailing() {
+        let quoted = if spread.is_some() || !slots.is_empty() {
+            let (props, names, values) = match spread {
+                Some(PropsSpread { fields, base, .. }) => (
+                    base.to_token_stream(),
+                    fields.iter().map(|field| &field.name).collect(),
+                    fields.iter().map(|field| &field.value).collect(),
+                ),
+                None => (args.to_token_stream(), Vec::new(), Vec::new()),
+            };
+            // The type of the props is taken from `__create_component` so that the fields can be
+            // assigned even when it can not be inferred from the props alone, e.g. for
+            // `..Default::default()`.
+            quote_spanned! { path.span()=>
+                ::sycamore::reactive::untrack(|| {
+                    #[allow(unused_imports)]
+                    use ::sycamore::component::Component as __Component;
+                    fn __props_of<P, V>(_: &impl ::std::ops::Fn(P) -> V, props: P) -> P {
+                        props
+                    }
+                    let __create = #path#generics::__create_component;
+                    #[allow(unused_mut)]
+                    let mut __props = __props_of(&__create, #props);
+                    #( __props.#names = #values; )*
+                    #( #slots )*
+                    __create(__props)
+                })
+            }
+        } else if args.empty_or_trailing() {
             quote_spanned! { paren.span=>
                 ::sycamore::reactive::untrack(|| {
                     #[allow(unused_imports)]
@@ -108,10 +108,11 @@ impl ToTokens for Element {
                     HtmlTree::Splice(splice @ Splice {
                         expr: Expr::Lit(_) | Expr::Path(_), ..
                     }) => {
+                        let create_view = splice.create_view();
                         quote_spanned! { splice.span()=>
                             ::sycamore::utils::render::insert(
                                 &__el,
-                                ::sycamore::view::IntoView::create(&#splice),
+                                #create_view,
                                 None, None, #multi
                             );
                         }
@@ -156,16 +157,19 @@ impl ToTokens for Element {
                                     None, __marker, #multi
                                 );
                             },
-                            HtmlTree::Splice(splice) => quote_spanned! { splice.span()=>
-                                #quote_marker
-                                ::sycamore::utils::render::insert(
-                                   &__el,
-                                   ::sycamore::view::View::new_dyn(move ||
-                                       ::sycamore::view::IntoView::create(&#splice)
-                                   ),
-                                   None, __marker, #multi
-                               );
-                            },
+                            HtmlTree::Splice(splice) => {
+                                let create_view = splice.create_view();
+                                quote_spanned! { splice.span()=>
+                                    #quote_marker
+                                    ::sycamore::utils::render::insert(
+                                       &__el,
+                                       ::sycamore::view::View::new_dyn(move ||
+                                           #create_view
+                                       ),
+                                       None, __marker, #multi
+                                   );
+                                }
+                            }
                             _ => unreachable!()
                         }
                     }
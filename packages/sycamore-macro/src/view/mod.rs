@@ -88,11 +88,14 @@ impl ToTokens for HtmlTree {
                     ::sycamore::generic_node::GenericNode::text_node(#text),
                 )
             },
-            Self::Splice(splice) => quote! {
-                ::sycamore::view::View::new_dyn(move ||
-                    ::sycamore::view::IntoView::create(&#splice)
-                )
-            },
+            Self::Splice(splice) => {
+                let create_view = splice.create_view();
+                quote! {
+                    ::sycamore::view::View::new_dyn(move ||
+                        #create_view
+                    )
+                }
+            }
         };
 
         tokens.extend(quoted);
@@ -1,5 +1,5 @@
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::token::Paren;
 use syn::{parenthesized, Expr, Result};
@@ -20,6 +20,19 @@ impl Parse for Splice {
     }
 }
 
+impl Splice {
+    /// Returns an expression that creates a `View` from the spliced expression. `Option<View<G>>`
+    /// is rendered as an empty view if it is `None`. All other types are rendered with `IntoView`.
+    pub fn create_view(&self) -> TokenStream {
+        let expr = &self.expr;
+        quote! {{
+            #[allow(unused_imports)]
+            use ::sycamore::rt::{SpliceIntoView as _, SpliceOptionView as _};
+            (&::sycamore::rt::Splice(&(#expr))).create_splice()
+        }}
+    }
+}
+
 impl ToTokens for Splice {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         // match self {
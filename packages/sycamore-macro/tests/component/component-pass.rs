@@ -10,4 +10,10 @@ fn comp2(_props: ()) -> View<G> {
     todo!();
 }
 
+#[component(Comp3<G>)]
+fn comp3(value: String) -> Result<View<G>, std::num::ParseIntError> {
+    let value: i32 = value.parse()?;
+    Ok(view! { (value) })
+}
+
 fn main() {}
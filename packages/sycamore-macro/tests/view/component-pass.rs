@@ -7,8 +7,83 @@ pub fn component() -> View<G> {
     }
 }
 
+pub struct ButtonProps {
+    pub label: String,
+    pub class: String,
+}
+
+#[component(Button<G>)]
+pub fn button(props: ButtonProps) -> View<G> {
+    let ButtonProps { label, class } = props;
+    view! {
+        button(class=class) { (label) }
+    }
+}
+
+#[component(PrimaryButton<G>)]
+pub fn primary_button(props: ButtonProps) -> View<G> {
+    view! {
+        Button(class: "primary".to_string(), ..props)
+    }
+}
+
+#[derive(Slots)]
+pub struct CardProps<G: GenericNode> {
+    pub class: String,
+    #[slot]
+    pub header: View<G>,
+    #[slot]
+    pub body: View<G>,
+}
+
+impl<G: GenericNode> Default for CardProps<G> {
+    fn default() -> Self {
+        Self {
+            class: String::new(),
+            header: View::empty(),
+            body: View::empty(),
+        }
+    }
+}
+
+#[component(Card<G>)]
+pub fn card(props: CardProps<G>) -> View<G> {
+    let CardProps {
+        class,
+        header,
+        body,
+    } = props;
+    view! {
+        div(class=class) { (header) (body) }
+    }
+}
+
 fn compile_pass<G: Html>() {
     let _: View<G> = view! { Component() };
+
+    let props = ButtonProps {
+        label: "Click me".to_string(),
+        class: "default".to_string(),
+    };
+    let _: View<G> = view! { Button(..props) };
+
+    let props = ButtonProps {
+        label: "Click me".to_string(),
+        class: "default".to_string(),
+    };
+    let _: View<G> = view! { Button(label: "Overridden".to_string(), class: String::new(), ..props) };
+
+    let _: View<G> = view! {
+        Card(CardProps::default()) {
+            Slot(name="header") { h1 { "Title" } }
+            Slot(name="body") { "Some" "text" }
+        }
+    };
+    let _: View<G> = view! {
+        Card(class: "card".to_string(), ..Default::default()) {
+            Slot(name="body") {}
+        }
+    };
 }
 
 fn main() {}
@@ -11,9 +11,29 @@ fn compile_pass<G: GenericNode>() {
     let _: View<G> = view! { p(class="my-class", id="my-id") };
 
     let _: View<G> = view! { button(class="my-btn", on:click=|_| {}) };
+    let _: View<G> = view! { div(on:item-selected=|_| {}) };
     let _: View<G> = view! { button(class="my-btn", aria-hidden="true") };
 
     let _: View<G> = view! { p(dangerously_set_inner_html="<span>Test</span>") };
+
+    let classes = Signal::new(vec!["a", "b"]);
+    let _: View<G> = view! { p(classes=classes) };
+
+    let active = Signal::new(true);
+    let _: View<G> = cloned!((active) => view! { p(class=("base", active => *active.get())) });
+    let _: View<G> = view! { p(class=("base", is-active => *active.get(), "x" => true,)) };
+    let _: View<G> = view! { p(class=("not toggles")) };
+
+    let _: View<G> = view! { p(ref=|node: G| drop(node)) };
+    let _: View<G> = view! { input(ref=|node| GenericNode::focus(&node)) };
+}
+
+fn compile_pass_html<G: Html>() {
+    let _: View<G> = view! { input(select_on_focus=true) };
+    let _: View<G> = view! { input(focus:auto=true) };
+
+    let note = Signal::new(String::new());
+    let _: View<G> = view! { div(contenteditable=true, bind:textContent=note) };
 }
 
 fn main() {}
@@ -0,0 +1,199 @@
+use std::fmt;
+use std::ops::{Add, Deref, Div, Mul, Neg, Rem, Sub};
+
+use super::*;
+
+/// A [`ReadSignal`] that can be combined with arithmetic operators.
+///
+/// Applying an operator to a [`Computed`] creates a new selector (see [`create_selector`]) that
+/// recomputes the result whenever one of the operands changes. Only the operands are tracked:
+/// building an expression never reads a signal in the current reactive scope, so it does not add
+/// any dependencies to the effect it is created in.
+///
+/// Every operator creates a new selector that is owned by the current reactive scope. Build
+/// expressions once, e.g. in the body of a component, rather than inside an effect that re-runs.
+///
+/// Operators are implemented between two [`Computed`]s and between a [`Computed`] of a primitive
+/// number and a plain number of the same type. Use [`Deref`] or [`Computed::into_signal`] to get
+/// the underlying [`ReadSignal`].
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let a: Signal<i32> = Signal::new(1);
+/// let b: Signal<i32> = Signal::new(2);
+/// let total = (a.computed() + b.computed()) * 10;
+/// assert_eq!(*total.get(), 30);
+///
+/// a.set(2);
+/// assert_eq!(*total.get(), 40);
+/// ```
+pub struct Computed<T: 'static>(ReadSignal<T>);
+
+impl<T: 'static> Computed<T> {
+    /// Returns the [`ReadSignal`] holding the result of the expression.
+    pub fn into_signal(self) -> ReadSignal<T> {
+        self.0
+    }
+}
+
+impl<T: 'static> ReadSignal<T> {
+    /// Wraps this signal in a [`Computed`] so that it can be used with arithmetic operators.
+    pub fn computed(&self) -> Computed<T> {
+        Computed(self.clone())
+    }
+}
+
+impl<T: 'static> From<ReadSignal<T>> for Computed<T> {
+    fn from(signal: ReadSignal<T>) -> Self {
+        Self(signal)
+    }
+}
+
+impl<T: 'static> From<Computed<T>> for ReadSignal<T> {
+    fn from(computed: Computed<T>) -> Self {
+        computed.0
+    }
+}
+
+impl<T: 'static> Deref for Computed<T> {
+    type Target = ReadSignal<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: 'static> Clone for Computed<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Computed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Computed")
+            .field(&self.0.get_untracked())
+            .finish()
+    }
+}
+
+impl<T> Neg for Computed<T>
+where
+    T: Neg + Clone + 'static,
+    T::Output: PartialEq + 'static,
+{
+    type Output = Computed<T::Output>;
+
+    fn neg(self) -> Self::Output {
+        Computed(create_selector(move || -(*self.get()).clone()))
+    }
+}
+
+macro_rules! impl_ops {
+    ($($trait:ident $method:ident),*) => {$(
+        impl<T, U> $trait<Computed<U>> for Computed<T>
+        where
+            T: $trait<U> + Clone + 'static,
+            U: Clone + 'static,
+            T::Output: PartialEq + 'static,
+        {
+            type Output = Computed<T::Output>;
+
+            fn $method(self, rhs: Computed<U>) -> Self::Output {
+                Computed(create_selector(move || {
+                    (*self.get()).clone().$method((*rhs.get()).clone())
+                }))
+            }
+        }
+
+        impl_ops!(@primitive $trait $method,
+            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+        );
+    )*};
+    (@primitive $trait:ident $method:ident, $($t:ty),*) => {$(
+        impl $trait<$t> for Computed<$t> {
+            type Output = Computed<$t>;
+
+            fn $method(self, rhs: $t) -> Self::Output {
+                Computed(create_selector(move || (*self.get()).$method(rhs)))
+            }
+        }
+
+        impl $trait<Computed<$t>> for $t {
+            type Output = Computed<$t>;
+
+            fn $method(self, rhs: Computed<$t>) -> Self::Output {
+                Computed(create_selector(move || self.$method(*rhs.get())))
+            }
+        }
+    )*};
+}
+
+impl_ops!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_updates_when_either_operand_changes() {
+        let a = Signal::new(1);
+        let b = Signal::new(2);
+        let sum = a.computed() + b.computed();
+        assert_eq!(*sum.get(), 3);
+
+        a.set(10);
+        assert_eq!(*sum.get(), 12);
+        b.set(20);
+        assert_eq!(*sum.get(), 30);
+    }
+
+    #[test]
+    fn operators_with_primitives() {
+        let count: Signal<i32> = Signal::new(3);
+        let double = count.computed() * 2;
+        let offset = 100 - count.computed();
+        let negated = -count.computed();
+        assert_eq!((*double.get(), *offset.get(), *negated.get()), (6, 97, -3));
+
+        count.set(5);
+        assert_eq!((*double.get(), *offset.get(), *negated.get()), (10, 95, -5));
+
+        let ratio = Signal::new(1.0_f64).computed() / 4.0;
+        assert_eq!(*ratio.get(), 0.25);
+    }
+
+    #[test]
+    fn building_expression_does_not_track_operands() {
+        let a: Signal<i32> = Signal::new(1);
+        let runs = Signal::new(0);
+        let mut sum = None;
+        create_effect(cloned!((a, runs) => move || {
+            if sum.is_none() {
+                sum = Some(a.computed() + 1);
+            }
+            runs.set(*runs.get_untracked() + 1);
+        }));
+
+        a.set(2);
+        assert_eq!(*runs.get(), 1);
+    }
+
+    #[test]
+    fn equal_result_does_not_notify() {
+        let a: Signal<i32> = Signal::new(1);
+        let parity = a.computed() % 2;
+        let runs = Signal::new(0);
+        create_effect(cloned!((parity, runs) => move || {
+            parity.get();
+            runs.set(*runs.get_untracked() + 1);
+        }));
+
+        a.set(3);
+        assert_eq!(*runs.get(), 1);
+        a.set(4);
+        assert_eq!(*runs.get(), 2);
+    }
+}
@@ -37,28 +37,49 @@ impl<T: 'static> ContextAny for Context<T> {
 ///
 /// # Panics
 /// This function will `panic!` if the context is not found in the current scope or a parent scope.
+/// For a non panicking version, see [`try_use_context`].
 pub fn use_context<T: Clone + 'static>() -> T {
+    try_use_context().expect("context not found for type")
+}
+
+/// Get the value of a context in the current [`ReactiveScope`] or `None` if the context is not
+/// found in the current scope or a parent scope.
+pub fn try_use_context<T: Clone + 'static>() -> Option<T> {
     SCOPES.with(|scopes| {
         let scopes = scopes.borrow();
         let mut current = scopes.last().map(|s| Rc::clone(&s.0));
-        match current {
-            Some(_) => {
-                while let Some(scope) = &current {
-                    if let Some(context) = &scope.borrow().context {
-                        if let Some(value) = context.get_value().downcast_ref::<T>() {
-                            return value.clone();
-                        }
-                    }
-                    current = current.unwrap_throw().borrow().parent.0.upgrade();
+        while let Some(scope) = &current {
+            if let Some(context) = &scope.borrow().context {
+                if let Some(value) = context.get_value().downcast_ref::<T>() {
+                    return Some(value.clone());
                 }
-                panic!("context not found for type")
             }
-            None => panic!("context not found for type"),
+            current = current.unwrap_throw().borrow().parent.0.upgrade();
         }
+        None
     })
 }
 
 /// Creates a new [`ReactiveScope`] with a context and runs the supplied callback function.
+///
+/// The context value itself is not reactive. To share state that changes over time, provide a
+/// [`ReadSignal`] or [`Signal`]. Effects that read the signal returned by [`use_context`] are
+/// subscribed to it like to any other signal.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let counter = Signal::new(0);
+///
+/// create_context_scope(counter.handle(), || {
+///     // In a descendant component.
+///     let counter = use_context::<ReadSignal<i32>>();
+///     create_effect(move || println!("Counter is {}", counter.get()));
+/// }); // Prints "Counter is 0"
+///
+/// counter.set(1); // Prints "Counter is 1"
+/// ```
 pub fn create_context_scope<T: 'static, Out>(value: T, f: impl FnOnce() -> Out) -> Out {
     SCOPES.with(|scopes| {
         // Create a new ReactiveScope with a context.
@@ -71,3 +92,33 @@ pub fn create_context_scope<T: 'static, Out>(value: T, f: impl FnOnce() -> Out)
         out
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn context_signal_is_reactive() {
+        let counter = Signal::new(0);
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let _scope = create_root(cloned!((counter, seen) => move || {
+            create_context_scope(counter.handle(), || {
+                // A deeply nested descendant.
+                create_effect(move || {
+                    create_effect(cloned!((seen) => move || {
+                        let counter = use_context::<ReadSignal<i32>>();
+                        seen.borrow_mut().push(*counter.get());
+                    }));
+                });
+            });
+        }));
+        assert_eq!(*seen.borrow(), vec![0]);
+
+        counter.set(1);
+        counter.set(2);
+        assert_eq!(*seen.borrow(), vec![0, 1, 2]);
+    }
+}
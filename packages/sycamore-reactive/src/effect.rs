@@ -1,10 +1,11 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::future::Future;
 use std::hash::{Hash, Hasher};
 use std::rc::{Rc, Weak};
 use std::{mem, ptr};
 
 use ahash::AHashSet;
+use indexmap::IndexMap;
 use smallvec::SmallVec;
 use wasm_bindgen::prelude::*;
 
@@ -28,8 +29,39 @@ thread_local! {
     /// Explicit stack of [`ReactiveScope`]s.
     pub(super) static SCOPES: RefCell<Vec<ReactiveScope>> =
         RefCell::new(Vec::with_capacity(SCOPES_INITIAL_CAPACITY));
+    /// Stack of signals that are currently notifying their subscribers. The last element is the
+    /// signal that caused the currently running effect to re-run.
+    #[cfg(feature = "debug")]
+    pub(super) static TRIGGERED: RefCell<Vec<Vec<AnySigRef>>> = RefCell::new(Vec::new());
+    /// Subscribers that have been queued up inside a [`batch`]. `None` if not inside a batch.
+    pub(super) static BATCH: RefCell<Option<IndexMap<CallbackPtr, BatchedCallback>>> =
+        RefCell::new(None);
+    /// The number of [`batch`]es that are currently running plus the number of times
+    /// [`pause_reactivity`] has been called without a matching [`resume_reactivity`]. The queued
+    /// subscribers are called once this drops back to `0`.
+    static BATCH_DEPTH: Cell<usize> = Cell::new(0);
+    /// The number of times [`pause_reactivity`] has been called without a matching
+    /// [`resume_reactivity`].
+    static PAUSED: Cell<usize> = Cell::new(0);
+    /// Scopes without a parent. Used by [`debug_scope_tree`].
+    #[cfg(feature = "debug")]
+    pub(super) static ROOT_SCOPES: RefCell<Vec<ReactiveScopeWeak>> = RefCell::new(Vec::new());
+    /// Stack of writes queued up with [`Signal::defer_set`]. Each running effect has its own
+    /// frame which is flushed once the effect has finished running.
+    pub(super) static DEFERRED: RefCell<Vec<DeferredWrites>> = RefCell::new(Vec::new());
+    /// Functions queued up with [`after_effect`]. They are called once the effect that was running
+    /// when they were queued can be triggered again.
+    static AFTER_EFFECT: RefCell<Vec<Box<dyn FnOnce()>>> = RefCell::new(Vec::new());
+    /// The [`EffectId`] given to the next effect that is created.
+    static NEXT_EFFECT_ID: Cell<u64> = Cell::new(0);
+    /// The effects that have run inside [`record_effect_order`]. `None` if not recording.
+    #[cfg(feature = "test-utils")]
+    static EFFECT_LOG: RefCell<Option<Vec<EffectId>>> = RefCell::new(None);
 }
 
+/// Writes that have been deferred with [`Signal::defer_set`] inside a single effect run.
+pub(super) type DeferredWrites = Vec<Box<dyn FnOnce()>>;
+
 /// State of the current running effect.
 /// When the state is dropped, all dependencies are removed (both links and backlinks).
 ///
@@ -37,6 +69,8 @@ thread_local! {
 /// dependency tracking whereas [`ReactiveScope`] is used for resource cleanup. Each [`Listener`]
 /// contains a [`ReactiveScope`].
 pub(super) struct Listener {
+    /// The identity of the effect.
+    id: EffectId,
     /// Callback to run when the effect is recreated.
     pub(super) callback: Rc<RefCell<dyn FnMut()>>,
     /// A list of dependencies which trigger the effect.
@@ -60,12 +94,22 @@ impl Listener {
 #[derive(Default)]
 pub(crate) struct ReactiveScopeInner {
     /// Effects created in this scope.
-    effects: SmallVec<[Rc<RefCell<Option<Listener>>>; REACTIVE_SCOPE_EFFECTS_STACK_CAPACITY]>,
+    pub(super) effects:
+        SmallVec<[Rc<RefCell<Option<Listener>>>; REACTIVE_SCOPE_EFFECTS_STACK_CAPACITY]>,
     /// Callbacks to call when the scope is dropped.
     cleanup: Vec<Box<dyn FnOnce()>>,
     /// Contexts created in this scope.
     pub context: Option<Box<dyn ContextAny>>,
     pub parent: ReactiveScopeWeak,
+    /// The name of the scope, if created with [`create_scope_named`].
+    #[cfg(feature = "debug")]
+    pub(super) name: Option<String>,
+    /// Scopes that were created inside this scope.
+    #[cfg(feature = "debug")]
+    pub(super) children: Vec<ReactiveScopeWeak>,
+    /// The number of signals that were created inside this scope.
+    #[cfg(feature = "debug")]
+    pub(super) signals: usize,
 }
 
 /// Owns the effects created in the current reactive scope.
@@ -204,19 +248,190 @@ impl ReactiveScopeWeak {
 pub(super) type CallbackPtr = *const RefCell<dyn FnMut()>;
 
 #[derive(Clone)]
-pub(super) struct Callback(pub(super) Weak<RefCell<dyn FnMut()>>);
+pub(super) struct Callback {
+    callback: Weak<RefCell<dyn FnMut()>>,
+    /// The effect that this callback re-runs. Subscribers are called in the order of their ids.
+    pub(super) id: EffectId,
+}
 
 impl Callback {
     #[must_use = "returned value must be manually called"]
     pub fn try_callback(&self) -> Option<Rc<RefCell<dyn FnMut()>>> {
-        self.0.upgrade()
+        self.callback.upgrade()
     }
 
     pub fn as_ptr(&self) -> CallbackPtr {
-        Weak::as_ptr(&self.0)
+        Weak::as_ptr(&self.callback)
+    }
+
+    /// Calls the callback if it has not been destroyed yet.
+    pub fn call(&self) {
+        // subscriber might have already been destroyed in the case of nested effects
+        if let Some(callback) = self.try_callback() {
+            // Dropped after the callback has been released so that it can be triggered again.
+            let _after_effect = match callback.try_borrow_mut() {
+                Ok(mut callback) => {
+                    let after_effect = AfterEffectGuard;
+                    #[cfg(not(feature = "catch-panics"))]
+                    callback();
+                    #[cfg(feature = "catch-panics")]
+                    call_catching_panics(&mut *callback);
+                    after_effect
+                }
+                // Might already be inside a callback, if infinite loop.
+                // Do nothing if infinite loop.
+                Err(_) => return,
+            };
+        }
+    }
+}
+
+/// Calls `f` once the effect that is currently running has finished and can be triggered again.
+/// Must only be called while an effect is running, i.e. when a signal gets subscribed to.
+///
+/// This is needed when the currently running effect should observe a change that is caused by
+/// the effect itself, e.g. by subscribing to a signal. Only [`create_lazy_selector`] uses this.
+pub(super) fn after_effect(f: impl FnOnce() + 'static) {
+    AFTER_EFFECT.with(|after| after.borrow_mut().push(Box::new(f)));
+}
+
+/// Calls the functions queued up with [`after_effect`] when it is dropped. Must be dropped after
+/// the effect that was running has been released. The functions are discarded if the effect
+/// panicked.
+struct AfterEffectGuard;
+
+impl Drop for AfterEffectGuard {
+    fn drop(&mut self) {
+        // Functions are only queued while subscribing, at the end of an effect run, so the queue
+        // only holds the functions of the effect that has just finished.
+        let queued = AFTER_EFFECT.with(|after| mem::take(&mut *after.borrow_mut()));
+        if !std::thread::panicking() {
+            for f in queued {
+                f();
+            }
+        }
+    }
+}
+
+/// The frame of [`DEFERRED`] of a running effect. The frame is popped when this is dropped and the
+/// writes are applied in a [`batch`]. The writes are discarded if the effect panicked.
+struct DeferredFrame;
+
+impl DeferredFrame {
+    fn push() -> Self {
+        DEFERRED.with(|deferred| deferred.borrow_mut().push(Vec::new()));
+        Self
+    }
+}
+
+impl Drop for DeferredFrame {
+    fn drop(&mut self) {
+        let deferred = DEFERRED.with(|deferred| deferred.borrow_mut().pop());
+        if std::thread::panicking() {
+            return;
+        }
+        if let Some(deferred) = deferred.filter(|deferred| !deferred.is_empty()) {
+            batch(|| {
+                for set in deferred {
+                    set();
+                }
+            });
+        }
+    }
+}
+
+/// Calls `callback`, catching and logging any panic so that the remaining subscribers of a signal
+/// are still notified. The reactive stacks are restored to their state from before the call.
+///
+/// Note that the effect that panicked might not track all of its dependencies until it runs
+/// successfully again. Panics can only be caught if the binary is built with `panic = "unwind"`,
+/// which is not the default on `wasm32` targets.
+#[cfg(feature = "catch-panics")]
+fn call_catching_panics(callback: &mut dyn FnMut()) {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let snapshot = StackSnapshot::take();
+    if let Err(payload) = catch_unwind(AssertUnwindSafe(callback)) {
+        snapshot.restore();
+
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("<unknown>");
+        #[cfg(target_arch = "wasm32")]
+        web_sys::console::error_1(&format!("subscriber panicked: {}", message).into());
+        #[cfg(not(target_arch = "wasm32"))]
+        eprintln!("ERROR: subscriber panicked: {}", message);
     }
 }
 
+/// The sizes of the thread-local reactive stacks, used to unwind them after a panic.
+#[cfg(feature = "catch-panics")]
+struct StackSnapshot {
+    listeners: usize,
+    scopes: usize,
+    deferred: usize,
+    after_effect: usize,
+    batch_depth: usize,
+    #[cfg(feature = "debug")]
+    triggered: usize,
+}
+
+#[cfg(feature = "catch-panics")]
+impl StackSnapshot {
+    fn take() -> Self {
+        Self {
+            listeners: LISTENERS.with(|listeners| listeners.borrow().len()),
+            scopes: SCOPES.with(|scopes| scopes.borrow().len()),
+            deferred: DEFERRED.with(|deferred| deferred.borrow().len()),
+            after_effect: AFTER_EFFECT.with(|after| after.borrow().len()),
+            batch_depth: BATCH_DEPTH.with(Cell::get),
+            #[cfg(feature = "debug")]
+            triggered: TRIGGERED.with(|triggered| triggered.borrow().len()),
+        }
+    }
+
+    fn restore(self) {
+        LISTENERS.with(|listeners| listeners.borrow_mut().truncate(self.listeners));
+        // Drop the scopes and deferred writes outside of the borrow because dropping a scope runs
+        // its cleanup callbacks, which might access the stacks again.
+        let scopes = SCOPES.with(|scopes| split_off_at_most(&mut scopes.borrow_mut(), self.scopes));
+        drop(scopes);
+        let deferred =
+            DEFERRED.with(|deferred| split_off_at_most(&mut deferred.borrow_mut(), self.deferred));
+        drop(deferred);
+        let after = AFTER_EFFECT
+            .with(|after| split_off_at_most(&mut after.borrow_mut(), self.after_effect));
+        drop(after);
+        // Subscribers queued by a batch that was interrupted by the panic are discarded.
+        BATCH_DEPTH.with(|depth| depth.set(self.batch_depth));
+        if self.batch_depth == 0 {
+            let batch = BATCH.with(|batch| batch.borrow_mut().take());
+            drop(batch);
+        }
+        #[cfg(feature = "debug")]
+        TRIGGERED.with(|triggered| triggered.borrow_mut().truncate(self.triggered));
+    }
+}
+
+#[cfg(feature = "catch-panics")]
+fn split_off_at_most<T>(vec: &mut Vec<T>, len: usize) -> Vec<T> {
+    if vec.len() > len {
+        vec.split_off(len)
+    } else {
+        Vec::new()
+    }
+}
+
+/// A [`Callback`] that has been queued up inside a [`batch`].
+pub(super) struct BatchedCallback {
+    pub(super) callback: Callback,
+    /// The signals that were updated and caused this callback to be queued.
+    #[cfg(feature = "debug")]
+    pub(super) triggered_by: Vec<AnySigRef>,
+}
+
 /// A strong backlink to a [`Signal`] for any type `T`.
 #[derive(Clone)]
 pub(super) struct Dependency(pub(super) Rc<dyn AnySignalInner>);
@@ -240,8 +455,23 @@ impl PartialEq for Dependency {
 }
 impl Eq for Dependency {}
 
+/// An identity of an effect, obtained with [`current_effect_id`].
+///
+/// Ids are handed out in the order the effects are created. An effect that is recreated, e.g.
+/// because it is nested inside another effect that re-ran, gets a new id.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct EffectId(u64);
+
 /// Creates an effect on signals used inside the effect closure.
 ///
+/// # Execution order
+/// When a signal is updated, the effects that depend on it run in the order in which they were
+/// created (see [`EffectId`]). In particular, an outer effect always runs before the effects nested
+/// inside it, so that stale inner effects are disposed of instead of being run. Effects that are
+/// created while the subscribers of a signal are being notified are not notified by that update;
+/// they already see the new value when they run for the first time. The same order is used for
+/// the effects queued up inside a [`batch`].
+///
 /// # Example
 /// ```
 /// use sycamore_reactive::*;
@@ -265,11 +495,27 @@ where
 /// Internal implementation: use dynamic dispatch to reduce code bloat.
 fn _create_effect(mut effect: Box<dyn FnMut()>) {
     let listener: Rc<RefCell<Option<Listener>>> = Rc::new(RefCell::new(None));
+    let id = NEXT_EFFECT_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        EffectId(id)
+    });
+    // The scope the effect is created in. Every execution of the effect runs in a child scope of
+    // it, even when the effect is re-executed from somewhere else, so that contexts can be found.
+    let parent = current_scope();
 
     // Callback for when the effect's dependencies are triggered.
     let callback: Rc<RefCell<dyn FnMut()>> = Rc::new(RefCell::new({
         let listener = Rc::downgrade(&listener);
         move || {
+            #[cfg(feature = "test-utils")]
+            EFFECT_LOG.with(|log| {
+                if let Some(log) = log.borrow_mut().as_mut() {
+                    log.push(id);
+                }
+            });
+            let deferred = DeferredFrame::push();
+
             LISTENERS.with(|listeners| {
                 // Record initial context size to verify that it is the same after.
                 let initial_context_size = listeners.borrow().len();
@@ -293,7 +539,7 @@ fn _create_effect(mut effect: Box<dyn FnMut()>) {
 
                 // Run effect closure.
                 drop(listener_mut); // Drop the RefMut because Signals will access it inside the effect callback.
-                let new_scope = create_root(|| {
+                let new_scope = create_child_scope_in(parent.as_ref(), || {
                     effect();
                 });
                 let mut listener_mut = listener.borrow_mut();
@@ -313,11 +559,12 @@ fn _create_effect(mut effect: Box<dyn FnMut()>) {
                 // New dependencies are those that are in new dependencies but not in old
                 // dependencies.
                 for new_dependency in listener_ref.dependencies.difference(&old_dependencies) {
-                    new_dependency.signal().subscribe(Callback(Rc::downgrade(
+                    new_dependency.signal().subscribe(Callback {
                         // Reference the same closure we are in right now.
                         // When the dependency changes, this closure will be called again.
-                        &listener_ref.callback,
-                    )));
+                        callback: Rc::downgrade(&listener_ref.callback),
+                        id,
+                    });
                 }
 
                 // Remove reactive context.
@@ -329,10 +576,14 @@ fn _create_effect(mut effect: Box<dyn FnMut()>) {
                     "context size should not change before and after create_effect_initial"
                 );
             });
+
+            // Apply the writes that were deferred while running the effect.
+            drop(deferred);
         }
     }));
 
     *listener.borrow_mut() = Some(Listener {
+        id,
         callback: Rc::clone(&callback),
         dependencies: AHashSet::new(),
         scope: ReactiveScope::new(),
@@ -358,7 +609,236 @@ fn _create_effect(mut effect: Box<dyn FnMut()>) {
         }
     });
 
+    let after_effect = AfterEffectGuard;
     callback.borrow_mut()();
+    drop(after_effect);
+}
+
+/// Creates an effect that only runs while `cond` is `true`.
+///
+/// When `cond` becomes `false`, the effect is disposed and all its cleanup callbacks are run. When
+/// `cond` becomes `true` again, the effect is re-created.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let modal_open = Signal::new(false);
+/// let state = Signal::new(0);
+///
+/// create_effect_while(modal_open.handle(), cloned!((state) => move || {
+///     println!("State changed. New state value = {}", state.get());
+/// })); // Does not print anything because the modal is not open
+///
+/// modal_open.set(true); // Prints "State changed. New state value = 0"
+/// state.set(1); // Prints "State changed. New state value = 1"
+///
+/// modal_open.set(false);
+/// state.set(2); // Does not print anything
+/// ```
+pub fn create_effect_while<F>(cond: ReadSignal<bool>, effect: F)
+where
+    F: FnMut() + 'static,
+{
+    let effect = Rc::new(RefCell::new(effect));
+    // Use a selector to prevent re-creating the effect when cond is set to the same value.
+    let cond = create_selector(move || *cond.get());
+
+    create_effect(move || {
+        if *cond.get() {
+            let effect = Rc::clone(&effect);
+            create_effect(move || effect.borrow_mut()());
+        }
+    });
+}
+
+/// Creates an effect that runs `f` exactly once.
+///
+/// Signals read inside `f` are not tracked, so the effect is never re-executed. Unlike calling `f`
+/// directly, the effect belongs to the current reactive scope: cleanup callbacks registered with
+/// [`on_cleanup`] inside `f` run when the scope is dropped. This is useful for one-time setup, such
+/// as subscribing to an external event source.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let scope = create_root(|| {
+///     create_effect_once(|| {
+///         println!("Subscribed");
+///         on_cleanup(|| println!("Unsubscribed"));
+///     });
+/// }); // Prints "Subscribed"
+///
+/// drop(scope); // Prints "Unsubscribed"
+/// ```
+pub fn create_effect_once<F>(f: F)
+where
+    F: FnOnce() + 'static,
+{
+    let mut f = Some(f);
+    create_effect(move || {
+        if let Some(f) = f.take() {
+            untrack(f);
+        }
+    });
+}
+
+/// Creates an effect that runs `f` immediately and then again every time `trigger` is set.
+///
+/// Signals read inside `f` are not tracked, so only setting `trigger` re-executes the effect. Since
+/// [`Signal::set`] always notifies its subscribers, calling `trigger.set(())` re-runs `f` even
+/// though the value stays the same. This is useful for "refresh" actions which should run some code
+/// again on demand. Like any effect, cleanup callbacks registered with [`on_cleanup`] inside `f`
+/// run before it is re-executed.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let user_id = Signal::new(1);
+/// let refresh = Signal::new(());
+/// let loaded = Signal::new(Vec::new());
+///
+/// create_effect_on_trigger(refresh.handle(), cloned!((user_id, loaded) => move || {
+///     // Stands in for fetching the data of the user from a server.
+///     loaded.set(vec![*user_id.get()]);
+/// }));
+/// assert_eq!(*loaded.get(), vec![1]);
+///
+/// // Changing the user does not re-fetch the data until it is refreshed.
+/// user_id.set(2);
+/// assert_eq!(*loaded.get(), vec![1]);
+/// refresh.set(());
+/// assert_eq!(*loaded.get(), vec![2]);
+/// ```
+pub fn create_effect_on_trigger<F>(trigger: ReadSignal<()>, mut f: F)
+where
+    F: FnMut() + 'static,
+{
+    create_effect(move || {
+        trigger.get();
+        untrack(&mut f);
+    });
+}
+
+/// Creates an effect that writes the value returned by `f` to `output` every time it runs.
+///
+/// This is similar to [`create_memo`], except that the result is written to an existing
+/// [`Signal`] which can also be set from elsewhere. Writing to `output` never re-triggers the
+/// effect itself. To read the previous value of `output` inside `f`, use
+/// [`get_untracked`](ReadSignal::get_untracked). Reading `output` with [`get`](ReadSignal::get)
+/// would subscribe the effect to its own output.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let celsius = Signal::new(0.0);
+/// let fahrenheit = Signal::new(0.0);
+///
+/// create_effect_output(fahrenheit.clone(), cloned!((celsius) => move || {
+///     *celsius.get() * 9.0 / 5.0 + 32.0
+/// }));
+/// assert_eq!(*fahrenheit.get(), 32.0);
+///
+/// celsius.set(100.0);
+/// assert_eq!(*fahrenheit.get(), 212.0);
+///
+/// // The output can still be set directly.
+/// fahrenheit.set(0.0);
+/// assert_eq!(*fahrenheit.get(), 0.0);
+/// ```
+pub fn create_effect_output<T, F>(output: Signal<T>, mut f: F)
+where
+    T: 'static,
+    F: FnMut() -> T + 'static,
+{
+    create_effect(move || {
+        let value = f();
+        untrack(|| output.set(value));
+    });
+}
+
+/// Creates an effect like [`create_effect`] whose body can fail. Whenever `f` returns an `Err`, it
+/// is passed to `on_err` instead of being ignored or turned into a panic, which would leave the
+/// subscribers of the signal that triggered the effect only partially notified.
+///
+/// The signals that `f` read before returning the error are still tracked, so the effect is
+/// re-executed once they change. `on_err` itself is not tracked.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let input = Signal::new("1".to_string());
+/// let parsed = Signal::new(0);
+/// let error = Signal::new(None);
+///
+/// create_effect_fallible(
+///     cloned!((input, parsed, error) => move || {
+///         parsed.set(input.get().parse::<i32>()?);
+///         error.set(None);
+///         Ok(())
+///     }),
+///     cloned!((error) => move |err: std::num::ParseIntError| error.set(Some(err.to_string()))),
+/// );
+/// assert_eq!(*parsed.get(), 1);
+///
+/// input.set("abc".to_string());
+/// assert_eq!(*parsed.get(), 1);
+/// assert!(error.get().is_some());
+/// ```
+pub fn create_effect_fallible<F, E, H>(mut f: F, on_err: H)
+where
+    F: FnMut() -> Result<(), E> + 'static,
+    H: Fn(E) + 'static,
+{
+    create_effect(move || {
+        if let Err(err) = f() {
+            untrack(|| on_err(err));
+        }
+    });
+}
+
+/// Creates an effect like [`create_effect`], but calls `on_rerun` with the dependencies that
+/// changed before every re-execution of the effect. This is useful for finding out why an effect
+/// is running more often than expected.
+///
+/// `on_rerun` is not called for the initial execution of the effect.
+///
+/// _This API requires the following crate features to be activated: `debug`_
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let state = Signal::new(0);
+///
+/// create_effect_debug(
+///     cloned!((state) => move || {
+///         state.get();
+///     }),
+///     |changed| println!("Effect re-running because of {:?}", changed),
+/// );
+///
+/// state.set(1); // Prints "Effect re-running because of [AnySigRef(...)]"
+/// ```
+#[cfg(feature = "debug")]
+pub fn create_effect_debug<F, R>(mut effect: F, on_rerun: R)
+where
+    F: FnMut() + 'static,
+    R: Fn(&[AnySigRef]) + 'static,
+{
+    let mut initial = true;
+    create_effect(move || {
+        if !initial {
+            let changed = TRIGGERED.with(|triggered| triggered.borrow().last().cloned());
+            untrack(|| on_rerun(&changed.unwrap_or_default()));
+        }
+        initial = false;
+        effect();
+    });
 }
 
 /// Creates a memoized value from some signals. Also know as "derived stores".
@@ -384,6 +864,45 @@ where
     create_selector_with(derived, |_, _| false)
 }
 
+/// Creates a memo like [`create_memo`] that is only recomputed when one of `deps` changes.
+///
+/// Signals that are read inside `derived` are not tracked. This avoids recomputing an expensive
+/// value when a signal that it only depends on loosely, such as a configuration value, changes.
+/// The new value of such a signal is picked up the next time one of `deps` changes.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let input = Signal::new(2);
+/// let precision = Signal::new(1);
+///
+/// let formatted = create_memo_tracking(
+///     &[input.any_ref()],
+///     cloned!((input, precision) => move || format!("{:.*}", *precision.get(), *input.get() as f64)),
+/// );
+/// assert_eq!(*formatted.get(), "2.0");
+///
+/// precision.set(2);
+/// assert_eq!(*formatted.get(), "2.0"); // Not recomputed.
+///
+/// input.set(3);
+/// assert_eq!(*formatted.get(), "3.00");
+/// ```
+pub fn create_memo_tracking<F, Out>(deps: &[AnySigRef], mut derived: F) -> ReadSignal<Out>
+where
+    F: FnMut() -> Out + 'static,
+    Out: 'static,
+{
+    let deps = deps.to_vec();
+    create_memo(move || {
+        for dep in &deps {
+            dep.track();
+        }
+        untrack(&mut derived)
+    })
+}
+
 /// Creates a memoized value from some signals. Also know as "derived stores".
 /// Unlike [`create_memo`], this function will not notify dependents of a change if the output is
 /// the same. That is why the output of the function must implement [`PartialEq`].
@@ -447,6 +966,173 @@ where
     memo.as_ref().unwrap_throw().handle()
 }
 
+/// Creates a memoized value like [`create_selector`] that compares hashes instead of values.
+///
+/// The hash of the current value is cached, so every recomputation only hashes the new value once
+/// instead of comparing it to the old value with [`PartialEq`]. This is cheaper for large nested
+/// structures, where a full comparison has to walk both values.
+///
+/// Two different values can have the same hash. In that (very unlikely) case, subscribers are not
+/// notified of the change and the signal keeps the old value. Use [`create_selector`] if this is
+/// not acceptable.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let rows = Signal::new(vec![vec![1, 2], vec![3, 4]]);
+/// let transposed = create_selector_hashed(cloned!((rows) => move || {
+///     let rows = rows.get();
+///     (0..rows[0].len())
+///         .map(|i| rows.iter().map(|row| row[i]).collect::<Vec<_>>())
+///         .collect::<Vec<_>>()
+/// }));
+/// assert_eq!(*transposed.get(), vec![vec![1, 3], vec![2, 4]]);
+/// ```
+pub fn create_selector_hashed<F, Out>(derived: F) -> ReadSignal<Out>
+where
+    F: FnMut() -> Out + 'static,
+    Out: Hash + 'static,
+{
+    let hash = |value: &Out| {
+        let mut hasher = ahash::AHasher::default();
+        value.hash(&mut hasher);
+        hasher.finish()
+    };
+    // The hash of the current value. Only computed once the value is first compared.
+    let current = Cell::new(None);
+
+    create_selector_with(derived, move |old, new| {
+        let old_hash = current.get().unwrap_or_else(|| hash(old));
+        let new_hash = hash(new);
+        current.set(Some(new_hash));
+        old_hash == new_hash
+    })
+}
+
+/// Creates a memoized value like [`create_selector`] that holds `initial` until it is first
+/// needed. This is useful for expensive derivations, so that the UI can be rendered with a
+/// placeholder right away.
+///
+/// `derived` is not called until the returned signal gets its first subscriber. The subscriber
+/// first sees `initial` and is then re-run with the computed value. Reading the signal untracked
+/// never causes `derived` to be called. Once computed, the value is kept up to date like with
+/// [`create_selector`].
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let items = Signal::new(vec![3, 1, 2]);
+/// let sorted = create_lazy_selector(Vec::new(), cloned!((items) => move || {
+///     let mut sorted = (*items.get()).clone();
+///     sorted.sort_unstable();
+///     sorted
+/// }));
+/// assert!(sorted.get_untracked().is_empty()); // Not computed yet.
+///
+/// create_effect(cloned!((sorted) => move || {
+///     println!("{:?}", sorted.get()); // Prints "[]" and then "[1, 2, 3]"
+/// }));
+/// assert_eq!(*sorted.get(), vec![1, 2, 3]);
+/// ```
+pub fn create_lazy_selector<F, Out>(initial: Out, derived: F) -> ReadSignal<Out>
+where
+    F: FnMut() -> Out + 'static,
+    Out: PartialEq + 'static,
+{
+    let memo = Signal::new(initial);
+    let handle = memo.handle();
+
+    let parent = current_scope();
+    // Taken by the first subscription. Cleared on cleanup if the memo was never subscribed to,
+    // which would otherwise leak because `memo` owns the hook that references it.
+    let pending = Rc::new(RefCell::new(Some((memo, derived))));
+    // The scope of the effect that computes the value.
+    let scope = Rc::new(RefCell::new(None));
+
+    handle.on_first_subscribe({
+        let pending = Rc::clone(&pending);
+        let scope = Rc::clone(&scope);
+        move || {
+            if let Some((memo, mut derived)) = pending.borrow_mut().take() {
+                let parent = parent.clone();
+                let scope = Rc::clone(&scope);
+                after_effect(move || {
+                    *scope.borrow_mut() = Some(create_child_scope_in(parent.as_ref(), || {
+                        create_effect(move || {
+                            let new_value = derived();
+                            if *memo.get_untracked() != new_value {
+                                memo.set(new_value);
+                            }
+                        });
+                    }));
+                });
+            }
+        }
+    });
+    on_cleanup(move || {
+        drop(pending.borrow_mut().take());
+        drop(scope.borrow_mut().take());
+    });
+
+    handle
+}
+
+/// Creates a memoized value that is `true` when all of the `signals` are `true`.
+///
+/// Every input is tracked, not only the ones read before the result was known, so the output is
+/// updated as soon as any of the inputs flips. Dependents are only notified when the result itself
+/// changes. The result is `true` if `signals` is empty.
+///
+/// # Example
+/// Enabling a submit button only once every field is valid:
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let name_valid = Signal::new(false);
+/// let email_valid = Signal::new(true);
+///
+/// let can_submit = all_true(&[name_valid.handle(), email_valid.handle()]);
+/// let submit_disabled = create_memo(cloned!((can_submit) => move || !*can_submit.get()));
+/// assert!(*submit_disabled.get());
+///
+/// name_valid.set(true);
+/// assert!(!*submit_disabled.get());
+///
+/// email_valid.set(false);
+/// assert!(*submit_disabled.get());
+/// ```
+pub fn all_true(signals: &[ReadSignal<bool>]) -> ReadSignal<bool> {
+    let signals = signals.to_vec();
+    // Count instead of using `Iterator::all` so that every input is read and tracked.
+    create_selector(move || signals.iter().filter(|signal| *signal.get()).count() == signals.len())
+}
+
+/// Creates a memoized value that is `true` when at least one of the `signals` is `true`.
+///
+/// Every input is tracked, not only the ones read before the result was known, so the output is
+/// updated as soon as any of the inputs flips. Dependents are only notified when the result itself
+/// changes. The result is `false` if `signals` is empty.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let saving = Signal::new(false);
+/// let loading = Signal::new(false);
+///
+/// let busy = any_true(&[saving.handle(), loading.handle()]);
+/// assert!(!*busy.get());
+///
+/// loading.set(true);
+/// assert!(*busy.get());
+/// ```
+pub fn any_true(signals: &[ReadSignal<bool>]) -> ReadSignal<bool> {
+    let signals = signals.to_vec();
+    create_selector(move || signals.iter().filter(|signal| *signal.get()).count() > 0)
+}
+
 /// An alternative to [`Signal::new`] that uses a reducer to get the next value.
 ///
 /// It uses a reducer function that takes the previous value and a message and returns the next
@@ -494,33 +1180,352 @@ where
     (memo.into_handle(), Rc::new(dispatcher))
 }
 
-/// Run the passed closure inside an untracked dependency scope.
-///
-/// This does **NOT** create a new [`ReactiveScope`].
+/// Creates a cached value that is only recomputed when it is invalidated manually. This is useful
+/// for derived state that depends on things outside of the reactive system, such as the current
+/// time.
 ///
-/// See also [`StateHandle::get_untracked()`].
+/// Returns a [`ReadSignal`] with the cached value and an `invalidate` function. Calling
+/// `invalidate` calls `compute` again and notifies the subscribers. Like with [`create_memo`],
+/// signals read inside `compute` are tracked, so the value is also recomputed when they change.
 ///
 /// # Example
-///
 /// ```
-/// use sycamore_reactive::*;
+/// use std::cell::Cell;
+/// use std::rc::Rc;
 ///
-/// let state = Signal::new(1);
+/// use sycamore_reactive::*;
 ///
-/// let double = create_memo({
-///     let state = state.clone();
-///     move || untrack(|| *state.get() * 2)
-/// });
+/// // Not a signal, so changes are not tracked.
+/// let external = Rc::new(Cell::new(1));
 ///
-/// assert_eq!(*double.get(), 2);
+/// let (cached, invalidate) = create_cached(cloned!((external) => move || external.get() * 10));
+/// external.set(2);
+/// assert_eq!(*cached.get(), 10);
 ///
-/// state.set(2);
-/// // double value should still be old value because state was untracked
-/// assert_eq!(*double.get(), 2);
+/// invalidate();
+/// assert_eq!(*cached.get(), 20);
 /// ```
-pub fn untrack<T>(f: impl FnOnce() -> T) -> T {
-    let f = Rc::new(RefCell::new(Some(f)));
-    let g = Rc::clone(&f);
+pub fn create_cached<F, T>(compute: F) -> (ReadSignal<T>, impl Fn())
+where
+    F: Fn() -> T + 'static,
+    T: 'static,
+{
+    let trigger = Signal::new(());
+    let cached = create_memo({
+        let trigger = trigger.clone();
+        move || {
+            trigger.get();
+            compute()
+        }
+    });
+
+    (cached, move || trigger.set(()))
+}
+
+/// An alternative to [`Signal::new`] that also validates the value.
+///
+/// Returns the [`Signal`] holding the value and a [`ReadSignal`] with the validation error, which
+/// is `None` if the value is valid. The error is recomputed using [`create_selector`] whenever the
+/// value changes.
+///
+/// # Params
+/// * `initial` - The initial value of the state.
+/// * `validate` - A function that returns `Err` with an error message if the value is invalid.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let (email, error) = create_validated_signal(String::new(), |email| {
+///     if email.contains('@') {
+///         Ok(())
+///     } else {
+///         Err("Please enter a valid email address".to_string())
+///     }
+/// });
+/// assert!(error.get().is_some());
+///
+/// email.set("me@example.com".to_string());
+/// assert!(error.get().is_none());
+/// ```
+pub fn create_validated_signal<T: 'static>(
+    initial: T,
+    validate: impl Fn(&T) -> Result<(), String> + 'static,
+) -> (Signal<T>, ReadSignal<Option<String>>) {
+    let value = Signal::new(initial);
+    let error = create_selector(cloned!((value) => move || validate(&value.get()).err()));
+
+    (value, error)
+}
+
+/// Creates a local [`Signal`] that is initialized with the value of `prop`, for components that
+/// receive a value as a prop but edit it locally (an _uncontrolled_ component).
+///
+/// Writing to the returned signal never affects `prop`. If `reset_on_change` is `true`, the local
+/// signal is reset to the value of `prop` whenever `prop` changes, discarding local edits. If it is
+/// `false`, `prop` is only used as the initial value and later changes are ignored.
+///
+/// # Example
+/// A text field that starts out with the saved name but can be edited before saving. Loading a
+/// different user resets the field:
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let saved_name = Signal::new("Alice".to_string());
+/// let draft = create_signal_from_prop(saved_name.handle(), true);
+///
+/// draft.set("Alicia".to_string()); // The user types in the field.
+/// assert_eq!(*saved_name.get(), "Alice");
+///
+/// saved_name.set("Bob".to_string()); // A different user is loaded.
+/// assert_eq!(*draft.get(), "Bob");
+/// ```
+pub fn create_signal_from_prop<T: Clone + 'static>(
+    prop: ReadSignal<T>,
+    reset_on_change: bool,
+) -> Signal<T> {
+    let local = Signal::new((*prop.get_untracked()).clone());
+
+    if reset_on_change {
+        let mut initialized = false;
+        create_effect(cloned!((local) => move || {
+            let value = prop.get();
+            if initialized {
+                // Share the value of the prop instead of cloning it.
+                local.set_rc(value);
+            }
+            initialized = true;
+        }));
+    }
+
+    local
+}
+
+/// Creates a [`ReadSignal`] that counts how many times `signal` has notified its subscribers since
+/// this function was called.
+///
+/// The count starts at `0`. [`Signal::set`] always notifies, even when the new value is equal to
+/// the old one, so every call to it is counted. Setting a signal created with
+/// [`Signal::new_dedup`] to an equal value does not notify and is not counted. This is useful for
+/// debugging how often something re-renders, or for displaying the rate at which a value changes.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let query = Signal::new(String::new());
+/// let changes = create_change_count(query.handle());
+///
+/// query.set("a".to_string());
+/// query.set("ab".to_string());
+/// assert_eq!(*changes.get(), 2);
+/// ```
+pub fn create_change_count<T: 'static>(signal: ReadSignal<T>) -> ReadSignal<usize> {
+    let count = Signal::new(0);
+
+    let mut initialized = false;
+    create_effect(cloned!((count) => move || {
+        signal.get();
+        if initialized {
+            count.set(*count.get_untracked() + 1);
+        }
+        initialized = true;
+    }));
+
+    count.into_handle()
+}
+
+/// Runs the passed closure inside a batch. Subscribers of signals that are updated inside the batch
+/// are not notified until the end of the batch. This means that effects and memos depending on
+/// multiple signals that are updated inside the batch will only run once.
+///
+/// Batches can be nested. Subscribers are only notified at the end of the outermost batch.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let a = Signal::new(0);
+/// let b = Signal::new(0);
+/// let sum = create_memo(cloned!((a, b) => move || *a.get() + *b.get()));
+///
+/// batch(|| {
+///     a.set(1);
+///     b.set(2);
+///     // sum is not updated until the end of the batch
+///     assert_eq!(*sum.get(), 0);
+/// });
+/// assert_eq!(*sum.get(), 3);
+/// ```
+pub fn batch<T>(f: impl FnOnce() -> T) -> T {
+    start_batch();
+    // Closes the batch if `f` panics. Otherwise, the batch would stay open and every later update
+    // would be queued forever.
+    let guard = BatchGuard;
+    let ret = f();
+    mem::forget(guard);
+    end_batch();
+
+    ret
+}
+
+/// Closes a [`batch`] whose closure panicked. The queued subscribers are discarded instead of
+/// being called while unwinding.
+struct BatchGuard;
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        let is_outermost = BATCH_DEPTH.with(|depth| {
+            depth.set(depth.get().saturating_sub(1));
+            depth.get() == 0
+        });
+        if is_outermost {
+            // Drop the queue outside of the borrow because dropping callbacks can run code that
+            // accesses it again.
+            let queued = BATCH.with(|batch| batch.borrow_mut().take());
+            drop(queued);
+        }
+    }
+}
+
+/// Pauses all reactivity until [`resume_reactivity`] is called. Subscribers of signals that are
+/// updated while reactivity is paused are not notified until reactivity is resumed. Each
+/// subscriber is then only notified once, no matter how many of its dependencies were updated.
+///
+/// This is like [`batch`], except that it is not limited to a single closure. This is useful when
+/// updating a lot of state at once, e.g. when restoring a snapshot.
+///
+/// Calls to `pause_reactivity` can be nested. Reactivity is resumed once
+/// [`resume_reactivity`] has been called as many times as `pause_reactivity`.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let a = Signal::new(0);
+/// let b = Signal::new(0);
+/// let sum = create_memo(cloned!((a, b) => move || *a.get() + *b.get()));
+///
+/// pause_reactivity();
+/// a.set(1);
+/// b.set(2);
+/// assert_eq!(*sum.get(), 0);
+///
+/// resume_reactivity();
+/// assert_eq!(*sum.get(), 3);
+/// ```
+pub fn pause_reactivity() {
+    PAUSED.with(|paused| paused.set(paused.get() + 1));
+    start_batch();
+}
+
+/// Resumes reactivity after it has been paused with [`pause_reactivity`] and notifies all the
+/// subscribers of the signals that were updated in the meantime.
+///
+/// If reactivity is not paused, this does nothing.
+pub fn resume_reactivity() {
+    let was_paused = PAUSED.with(|paused| match paused.get() {
+        0 => false,
+        n => {
+            paused.set(n - 1);
+            true
+        }
+    });
+    if was_paused {
+        end_batch();
+    }
+}
+
+/// Returns `true` if reactivity is paused with [`pause_reactivity`].
+pub fn is_reactivity_paused() -> bool {
+    PAUSED.with(|paused| paused.get() > 0)
+}
+
+/// Returns the number of effects and memos that are waiting to be run because a signal they depend
+/// on was updated inside a [`batch`] or while reactivity is paused with [`pause_reactivity`].
+///
+/// Outside of a batch, effects run as soon as their dependencies are updated, so this is always
+/// `0`. This is mostly useful in tests for checking that all updates have settled.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let state = Signal::new(0);
+/// create_effect(cloned!((state) => move || {
+///     state.get();
+/// }));
+///
+/// pause_reactivity();
+/// state.set(1);
+/// assert_eq!(pending_effect_count(), 1);
+///
+/// resume_reactivity();
+/// assert_eq!(pending_effect_count(), 0);
+/// ```
+pub fn pending_effect_count() -> usize {
+    BATCH.with(|batch| batch.borrow().as_ref().map_or(0, IndexMap::len))
+}
+
+fn start_batch() {
+    BATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    BATCH.with(|batch| {
+        let mut batch = batch.borrow_mut();
+        if batch.is_none() {
+            *batch = Some(IndexMap::new());
+        }
+    });
+}
+
+/// Calls the queued subscribers if this is the end of the outermost batch.
+fn end_batch() {
+    let is_outermost = BATCH_DEPTH.with(|depth| {
+        depth.set(depth.get() - 1);
+        depth.get() == 0
+    });
+
+    if is_outermost {
+        let mut queued = BATCH.with(|batch| batch.borrow_mut().take().unwrap_throw());
+        queued.sort_by(|_, a, _, b| a.callback.id.cmp(&b.callback.id));
+        for batched in queued.values() {
+            #[cfg(feature = "debug")]
+            TRIGGERED.with(|triggered| triggered.borrow_mut().push(batched.triggered_by.clone()));
+
+            batched.callback.call();
+
+            #[cfg(feature = "debug")]
+            TRIGGERED.with(|triggered| triggered.borrow_mut().pop());
+        }
+    }
+}
+
+/// Run the passed closure inside an untracked dependency scope.
+///
+/// This does **NOT** create a new [`ReactiveScope`].
+///
+/// See also [`StateHandle::get_untracked()`].
+///
+/// # Example
+///
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let state = Signal::new(1);
+///
+/// let double = create_memo({
+///     let state = state.clone();
+///     move || untrack(|| *state.get() * 2)
+/// });
+///
+/// assert_eq!(*double.get(), 2);
+///
+/// state.set(2);
+/// // double value should still be old value because state was untracked
+/// assert_eq!(*double.get(), 2);
+/// ```
+pub fn untrack<T>(f: impl FnOnce() -> T) -> T {
+    let f = Rc::new(RefCell::new(Some(f)));
+    let g = Rc::clone(&f);
 
     // Do not panic if running inside destructor.
     if let Ok(ret) = LISTENERS.try_with(|listeners| {
@@ -610,6 +1615,100 @@ pub fn dependency_count() -> Option<usize> {
     })
 }
 
+/// Returns the [`EffectId`] of the currently running effect or `None` if called outside of an
+/// effect or inside [`untrack`].
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// assert_eq!(current_effect_id(), None);
+/// create_effect(|| assert!(current_effect_id().is_some()));
+/// ```
+pub fn current_effect_id() -> Option<EffectId> {
+    LISTENERS.with(|listeners| {
+        listeners.borrow().last().map(|last_context| {
+            last_context
+                .upgrade()
+                .expect_throw("Running should be valid while inside reactive scope")
+                .borrow()
+                .as_ref()
+                .unwrap_throw()
+                .id
+        })
+    })
+}
+
+/// Calls `f` and returns the ids of the effects that ran while it was running, in the order they
+/// ran. Both the first run of newly created effects and re-runs are recorded. This is useful for
+/// asserting the [execution order](create_effect#execution-order) of effects in tests.
+///
+/// _This API requires the following crate features to be activated: `test-utils`_
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let state = Signal::new(0);
+/// let ids = record_effect_order(|| {
+///     for _ in 0..2 {
+///         create_effect(cloned!((state) => move || {
+///             state.get();
+///         }));
+///     }
+/// });
+///
+/// assert_eq!(record_effect_order(|| state.set(1)), ids);
+/// ```
+#[cfg(feature = "test-utils")]
+pub fn record_effect_order(f: impl FnOnce()) -> Vec<EffectId> {
+    let outer = EFFECT_LOG.with(|log| log.borrow_mut().replace(Vec::new()));
+    f();
+    let ran = EFFECT_LOG.with(|log| mem::replace(&mut *log.borrow_mut(), outer).unwrap_throw());
+    // Effects that ran inside a nested recording also ran inside this one.
+    EFFECT_LOG.with(|log| {
+        if let Some(outer) = log.borrow_mut().as_mut() {
+            outer.extend_from_slice(&ran);
+        }
+    });
+    ran
+}
+
+/// Returns the identities of the signals that the currently running effect has tracked so far in
+/// this run. The order of the returned signals is unspecified.
+///
+/// Returns an empty `Vec` if called outside of an effect or inside [`untrack`]. This is mostly
+/// useful for asserting in tests that an effect tracks exactly the expected signals.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let a = Signal::new(1);
+/// let b = Signal::new(2);
+/// create_effect(cloned!((a, b) => move || {
+///     a.get();
+///     assert_eq!(current_dependencies(), vec![a.identity()]);
+///     b.get();
+///     assert_eq!(current_dependencies().len(), 2);
+/// }));
+/// ```
+pub fn current_dependencies() -> Vec<SignalId> {
+    LISTENERS.with(|listeners| match listeners.borrow().last() {
+        Some(last_context) => last_context
+            .upgrade()
+            .expect_throw("Running should be valid while inside reactive scope")
+            .borrow()
+            .as_ref()
+            .unwrap_throw()
+            .dependencies
+            .iter()
+            .map(|dependency| SignalId(Rc::as_ptr(&dependency.0).cast()))
+            .collect(),
+        None => Vec::new(),
+    })
+}
+
 /// Returns a [`ReactiveScopeWeak`] handle to the current reactive scope or `None` if outside of a
 /// reactive scope.
 pub fn current_scope() -> Option<ReactiveScopeWeak> {
@@ -672,6 +1771,88 @@ mod tests {
         assert_eq!(*counter.get(), 2);
     }
 
+    #[test]
+    #[cfg(feature = "catch-panics")]
+    fn panicking_subscriber_does_not_skip_others() {
+        let state = Signal::new(0);
+
+        // Subscribers are notified in the order they were created, so this one runs first.
+        create_effect(cloned!((state) => move || {
+            if *state.get() == 1 {
+                panic!("bad subscriber");
+            }
+        }));
+        let counter = Signal::new(0);
+        create_effect(cloned!((state, counter) => move || {
+            state.get();
+            counter.set(*counter.get_untracked() + 1);
+        }));
+        assert_eq!(*counter.get(), 1);
+
+        state.set(1);
+        assert_eq!(*counter.get(), 2);
+
+        // The reactive stacks are restored after the panic.
+        assert_eq!(dependency_count(), None);
+        batch(|| state.set(2));
+        assert_eq!(*counter.get(), 3);
+    }
+
+    #[test]
+    fn effect_fallible_routes_errors_to_handler() {
+        let state = Signal::new(0);
+
+        let errors = Signal::new(Vec::new());
+        create_effect_fallible(
+            cloned!((state) => move || {
+                if *state.get() < 0 {
+                    return Err(*state.get_untracked());
+                }
+                Ok(())
+            }),
+            cloned!((errors) => move |err| errors.push(err)),
+        );
+
+        // Other subscribers of `state` are still notified.
+        let counter = Signal::new(0);
+        create_effect(cloned!((state, counter) => move || {
+            state.get();
+            counter.set(*counter.get_untracked() + 1);
+        }));
+
+        state.set(-1);
+        assert_eq!(*errors.get(), vec![-1]);
+        assert_eq!(*counter.get(), 2);
+
+        // The effect still tracks its dependencies after an error.
+        state.set(-2);
+        state.set(1);
+        assert_eq!(*errors.get(), vec![-1, -2]);
+        assert_eq!(*counter.get(), 4);
+    }
+
+    #[test]
+    fn current_dependencies_lists_tracked_signals() {
+        let a = Signal::new(0);
+        let b = Signal::new(0);
+        let untracked = Signal::new(0);
+
+        let dependencies = Rc::new(RefCell::new(Vec::new()));
+        create_effect(cloned!((a, b, untracked, dependencies) => move || {
+            a.get();
+            b.get();
+            untracked.get_untracked();
+            *dependencies.borrow_mut() = current_dependencies();
+        }));
+
+        let mut expected = vec![a.identity(), b.identity()];
+        expected.sort();
+        dependencies.borrow_mut().sort();
+        assert_eq!(*dependencies.borrow(), expected);
+
+        assert!(current_dependencies().is_empty());
+    }
+
     #[test]
     fn effect_should_recreate_dependencies() {
         let condition = Signal::new(true);
@@ -820,6 +2001,89 @@ mod tests {
         assert_eq!(*inner_counter.get(), 2);
     }
 
+    #[test]
+    fn outer_effects_rerun_first_after_inner_recreated() {
+        let trigger = Signal::new(());
+        let order = Signal::new(Vec::new());
+
+        create_effect(cloned!((trigger, order) => move || {
+            trigger.get();
+            order.push("outer");
+
+            create_effect(cloned!((trigger, order) => move || {
+                trigger.get();
+                order.push("inner");
+            }));
+        }));
+
+        // The recreated inner effect is subscribed after the outer effect but must still run
+        // after it, i.e. not at all since the outer effect recreates it.
+        for _ in 0..3 {
+            order.set(Vec::new());
+            trigger.set(());
+            assert_eq!(*order.get(), ["outer", "inner"]);
+        }
+    }
+
+    #[test]
+    fn effects_created_while_notifying_are_not_notified() {
+        let state = Signal::new(0);
+        let seen = Signal::new(Vec::new());
+        let roots = Rc::new(RefCell::new(Vec::new()));
+
+        create_effect(cloned!((state, seen, roots) => move || {
+            if *state.get() == 1 {
+                // Not nested inside this effect, so it is not disposed when this effect re-runs.
+                roots.borrow_mut().push(create_root(cloned!((state, seen) => move || {
+                    create_effect(cloned!((state, seen) => move || seen.push(*state.get())));
+                })));
+            }
+        }));
+
+        state.set(1);
+        assert_eq!(*seen.get(), [1]);
+
+        state.set(2);
+        assert_eq!(*seen.get(), [1, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "test-utils")]
+    fn record_nested_effect_order() {
+        let trigger = Signal::new(());
+        let names = Rc::new(RefCell::new(std::collections::HashMap::new()));
+        let name = cloned!((names) => move |name: &'static str| {
+            names.borrow_mut().insert(current_effect_id().unwrap(), name);
+        });
+
+        create_effect(cloned!((trigger, name) => move || {
+            trigger.get();
+            name("outer");
+            create_effect(cloned!((trigger, name) => move || {
+                trigger.get();
+                name("first inner");
+            }));
+            create_effect(cloned!((trigger, name) => move || {
+                trigger.get();
+                name("second inner");
+            }));
+        }));
+        create_effect(cloned!((trigger, name) => move || {
+            trigger.get();
+            name("sibling");
+        }));
+
+        for _ in 0..2 {
+            let ran = record_effect_order(|| trigger.set(()));
+            let ran: Vec<_> = ran.iter().map(|id| names.borrow()[id]).collect();
+            assert_eq!(ran, ["outer", "first inner", "second inner", "sibling"]);
+
+            let ran = record_effect_order(|| batch(|| trigger.set(())));
+            let ran: Vec<_> = ran.iter().map(|id| names.borrow()[id]).collect();
+            assert_eq!(ran, ["outer", "first inner", "second inner", "sibling"]);
+        }
+    }
+
     #[test]
     fn drop_signal_inside_effect() {
         let state = RefCell::new(Some(Signal::new(0)));
@@ -855,6 +2119,372 @@ mod tests {
         assert_eq!(*counter.get(), 2); // inner effect should be destroyed and thus not executed
     }
 
+    #[test]
+    #[cfg(feature = "debug")]
+    fn effect_debug_reports_changed_signal() {
+        let state1 = Signal::new(0);
+        let state2 = Signal::new(0);
+
+        let reported = Rc::new(RefCell::new(Vec::new()));
+
+        create_effect_debug(
+            cloned!((state1, state2) => move || {
+                state1.get();
+                state2.get();
+            }),
+            cloned!((reported) => move |changed: &[AnySigRef]| {
+                reported.borrow_mut().push(changed.to_vec());
+            }),
+        );
+        assert!(reported.borrow().is_empty()); // not called on initial run
+
+        state2.set(1);
+        assert_eq!(reported.borrow().len(), 1);
+        assert_eq!(reported.borrow()[0].len(), 1);
+        assert!(reported.borrow()[0][0].is(&state2));
+        assert!(!reported.borrow()[0][0].is(&state1));
+
+        state1.set(1);
+        assert_eq!(reported.borrow().len(), 2);
+        assert!(reported.borrow()[1][0].is(&state1));
+
+        batch(|| {
+            state1.set(2);
+            state2.set(2);
+        });
+        assert_eq!(reported.borrow().len(), 3);
+        assert_eq!(reported.borrow()[2].len(), 2);
+        assert!(reported.borrow()[2][0].is(&state1));
+        assert!(reported.borrow()[2][1].is(&state2));
+    }
+
+    #[test]
+    fn effect_while() {
+        let cond = Signal::new(true);
+        let state = Signal::new(0);
+
+        let counter = Signal::new(0);
+        let cleanup_counter = Signal::new(0);
+        create_effect_while(
+            cond.handle(),
+            cloned!((state, counter, cleanup_counter) => move || {
+                state.get(); // subscribe to state
+                counter.set(*counter.get_untracked() + 1);
+
+                on_cleanup(cloned!((cleanup_counter) => move || {
+                    cleanup_counter.set(*cleanup_counter.get_untracked() + 1);
+                }));
+            }),
+        );
+        assert_eq!(*counter.get(), 1);
+
+        state.set(1);
+        assert_eq!(*counter.get(), 2);
+        assert_eq!(*cleanup_counter.get(), 1);
+
+        cond.set(false);
+        assert_eq!(*cleanup_counter.get(), 2); // effect disposed
+
+        state.set(2);
+        assert_eq!(*counter.get(), 2); // effect should not respond while cond is false
+
+        cond.set(true);
+        assert_eq!(*counter.get(), 3); // effect re-created
+
+        cond.set(true);
+        assert_eq!(*counter.get(), 3); // setting cond to the same value does nothing
+
+        state.set(3);
+        assert_eq!(*counter.get(), 4);
+    }
+
+    #[test]
+    fn memo_tracking() {
+        let input = Signal::new(1);
+        let config = Signal::new(10);
+        let runs = Rc::new(Cell::new(0));
+
+        let memo = create_memo_tracking(
+            &[input.any_ref()],
+            cloned!((input, config, runs) => move || {
+                runs.set(runs.get() + 1);
+                *input.get() * *config.get()
+            }),
+        );
+        assert_eq!(*memo.get(), 10);
+        assert_eq!(runs.get(), 1);
+
+        // Reading config is not tracked.
+        config.set(100);
+        assert_eq!(*memo.get(), 10);
+        assert_eq!(runs.get(), 1);
+
+        input.set(2);
+        assert_eq!(*memo.get(), 200);
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn effect_once() {
+        let state = Signal::new(0);
+        let runs = Rc::new(Cell::new(0));
+        let cleanups = Rc::new(Cell::new(0));
+
+        let scope = create_root(cloned!((state, runs, cleanups) => move || {
+            create_effect_once(move || {
+                state.get();
+                runs.set(runs.get() + 1);
+                on_cleanup(move || cleanups.set(cleanups.get() + 1));
+            });
+        }));
+        assert_eq!(runs.get(), 1);
+
+        state.set(1);
+        assert_eq!(runs.get(), 1);
+        assert_eq!(cleanups.get(), 0);
+
+        drop(scope);
+        assert_eq!(cleanups.get(), 1);
+    }
+
+    #[test]
+    fn effect_on_trigger() {
+        let trigger = Signal::new(());
+        let state = Signal::new(0);
+        let runs = Rc::new(Cell::new(0));
+
+        create_effect_on_trigger(
+            trigger.handle(),
+            cloned!((state, runs) => move || {
+                state.get();
+                runs.set(runs.get() + 1);
+            }),
+        );
+        assert_eq!(runs.get(), 1);
+
+        // Signals read inside the effect are not tracked.
+        state.set(1);
+        assert_eq!(runs.get(), 1);
+
+        trigger.set(());
+        assert_eq!(runs.get(), 2);
+        trigger.set(());
+        assert_eq!(runs.get(), 3);
+    }
+
+    #[test]
+    fn effect_output() {
+        let state = Signal::new(1);
+        let output = Signal::new(0);
+
+        let counter = Signal::new(0);
+        create_effect_output(
+            output.clone(),
+            cloned!((state, output, counter) => move || {
+                counter.set(*counter.get_untracked() + 1);
+                *output.get_untracked() + *state.get()
+            }),
+        );
+        assert_eq!(*output.get(), 1);
+        assert_eq!(*counter.get(), 1);
+
+        // Downstream subscribers are notified.
+        let doubled = create_memo(cloned!((output) => move || *output.get() * 2));
+        assert_eq!(*doubled.get(), 2);
+
+        state.set(2);
+        assert_eq!(*output.get(), 3);
+        assert_eq!(*doubled.get(), 6);
+        assert_eq!(*counter.get(), 2); // writing to output does not re-run the effect
+
+        // Setting the output from elsewhere does not re-run the effect either.
+        output.set(10);
+        assert_eq!(*doubled.get(), 20);
+        assert_eq!(*counter.get(), 2);
+
+        state.set(1);
+        assert_eq!(*output.get(), 11);
+        assert_eq!(*counter.get(), 3);
+    }
+
+    #[test]
+    fn defer_set_is_glitch_free() {
+        let source = Signal::new(1);
+        let a = Signal::new(0);
+        let b = Signal::new(0);
+
+        create_effect(cloned!((source, a, b) => move || {
+            let value = *source.get();
+            a.defer_set(value);
+            // Not applied until the effect has finished running.
+            assert_eq!(*a.get_untracked(), value - 1);
+            b.defer_set(value * 2);
+        }));
+
+        // Records every (a, b) pair that is observed.
+        let observed = Signal::new(Vec::new());
+        create_effect(cloned!((a, b, observed) => move || {
+            let pair = (*a.get(), *b.get());
+            observed.set(observed.get_untracked().iter().copied().chain(Some(pair)).collect());
+        }));
+        assert_eq!(*observed.get(), vec![(1, 2)]);
+
+        source.set(2);
+        // The observer never sees an inconsistent state such as (2, 2).
+        assert_eq!(*observed.get(), vec![(1, 2), (2, 4)]);
+        assert_eq!(*a.get(), 2);
+        assert_eq!(*b.get(), 4);
+    }
+
+    #[test]
+    fn defer_set_in_panicking_effect_is_discarded() {
+        let state = Signal::new(0);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            create_effect(cloned!((state) => move || {
+                state.defer_set(1);
+                panic!("panic inside effect");
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(*state.get(), 0);
+
+        // The frame of the effect has been popped.
+        state.defer_set(2);
+        assert_eq!(*state.get(), 2);
+    }
+
+    #[test]
+    fn defer_set_outside_effect() {
+        let state = Signal::new(0);
+        state.defer_set(1);
+        assert_eq!(*state.get(), 1);
+    }
+
+    #[test]
+    fn batch_runs_effect_once() {
+        let state1 = Signal::new(0);
+        let state2 = Signal::new(0);
+
+        let counter = Signal::new(0);
+        create_effect(cloned!((state1, state2, counter) => move || {
+            counter.set(*counter.get_untracked() + 1);
+
+            state1.get();
+            state2.get();
+        }));
+        assert_eq!(*counter.get(), 1);
+
+        batch(|| {
+            state1.set(1);
+            state2.set(1);
+            assert_eq!(*counter.get(), 1); // effect not run inside batch
+        });
+        assert_eq!(*counter.get(), 2);
+    }
+
+    #[test]
+    fn nested_batch() {
+        let state = Signal::new(0);
+        let double = create_memo(cloned!((state) => move || *state.get() * 2));
+
+        batch(|| {
+            batch(|| state.set(1));
+            assert_eq!(*double.get(), 0); // still inside outer batch
+        });
+        assert_eq!(*double.get(), 2);
+    }
+
+    #[test]
+    fn panic_inside_batch_closes_batch() {
+        let state = Signal::new(0);
+        let double = create_memo(cloned!((state) => move || *state.get() * 2));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            batch(|| {
+                state.set(1);
+                panic!("panic inside batch");
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(pending_effect_count(), 0);
+
+        // Updates are not queued anymore.
+        state.set(2);
+        assert_eq!(*double.get(), 4);
+    }
+
+    #[test]
+    fn pause_reactivity_runs_effects_once_on_resume() {
+        let signals = (0..10).map(Signal::new).collect::<Vec<_>>();
+
+        let sum_runs = Signal::new(0);
+        create_effect(cloned!((signals, sum_runs) => move || {
+            signals.iter().for_each(|signal| {
+                signal.get();
+            });
+            sum_runs.set(*sum_runs.get_untracked() + 1);
+        }));
+        let first_runs = Signal::new(0);
+        create_effect(cloned!((signals, first_runs) => move || {
+            signals[0].get();
+            first_runs.set(*first_runs.get_untracked() + 1);
+        }));
+
+        pause_reactivity();
+        assert!(is_reactivity_paused());
+        for (i, signal) in signals.iter().enumerate() {
+            signal.set(i as i32 * 2);
+        }
+        // Batches inside a pause do not resume reactivity.
+        batch(|| signals[0].set(100));
+        assert_eq!(*sum_runs.get(), 1);
+        assert_eq!(*first_runs.get(), 1);
+
+        resume_reactivity();
+        assert!(!is_reactivity_paused());
+        assert_eq!(*sum_runs.get(), 2);
+        assert_eq!(*first_runs.get(), 2);
+    }
+
+    #[test]
+    fn pending_effect_count_counts_each_subscriber_once() {
+        let a = Signal::new(0);
+        let b = Signal::new(0);
+        create_effect(cloned!((a, b) => move || {
+            a.get();
+            b.get();
+        }));
+        let double = create_memo(cloned!((a) => move || *a.get() * 2));
+        assert_eq!(pending_effect_count(), 0);
+
+        batch(|| {
+            a.set(1);
+            b.set(1);
+            assert_eq!(pending_effect_count(), 2);
+        });
+        assert_eq!(pending_effect_count(), 0);
+        assert_eq!(*double.get(), 2);
+    }
+
+    #[test]
+    fn nested_pause_reactivity() {
+        let state = Signal::new(0);
+        let double = create_memo(cloned!((state) => move || *state.get() * 2));
+
+        pause_reactivity();
+        pause_reactivity();
+        state.set(1);
+        resume_reactivity();
+        assert_eq!(*double.get(), 0);
+        resume_reactivity();
+        assert_eq!(*double.get(), 2);
+
+        // Resuming when not paused does nothing.
+        resume_reactivity();
+        state.set(2);
+        assert_eq!(*double.get(), 4);
+    }
+
     #[test]
     fn memo() {
         let state = Signal::new(0);
@@ -939,6 +2569,194 @@ mod tests {
         assert_eq!(*counter.get(), 2);
     }
 
+    #[test]
+    fn selector_hashed_does_not_compare_values() {
+        thread_local! {
+            static EQ_CALLS: Cell<usize> = Cell::new(0);
+        }
+
+        #[derive(Clone)]
+        struct Item(u32);
+
+        impl Hash for Item {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state);
+            }
+        }
+
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                EQ_CALLS.with(|calls| calls.set(calls.get() + 1));
+                self.0 == other.0
+            }
+        }
+
+        let len = Signal::new(10_000);
+        let offset = Signal::new(0);
+        let items = create_selector_hashed(cloned!((len, offset) => move || {
+            (0..*len.get()).map(|i| Item(i + *offset.get())).collect::<Vec<_>>()
+        }));
+
+        let counter = Signal::new(0);
+        create_effect(cloned!((counter, items) => move || {
+            items.get();
+            counter.set(*counter.get_untracked() + 1);
+        }));
+        assert_eq!(*counter.get(), 1);
+
+        // Recomputes an equal value.
+        len.set(10_000);
+        assert_eq!(*counter.get(), 1);
+
+        offset.set(1);
+        assert_eq!(*counter.get(), 2);
+        assert_eq!(items.get()[0].0, 1);
+
+        // Changing back to a previous value is still a change.
+        offset.set(0);
+        assert_eq!(*counter.get(), 3);
+        assert_eq!(EQ_CALLS.with(Cell::get), 0);
+    }
+
+    #[test]
+    fn signal_from_prop() {
+        let prop = Signal::new(1);
+        let reset = create_signal_from_prop(prop.handle(), true);
+        let kept = create_signal_from_prop(prop.handle(), false);
+        assert_eq!((*reset.get(), *kept.get()), (1, 1));
+
+        reset.set(10);
+        kept.set(20);
+        assert_eq!(*prop.get(), 1);
+
+        prop.set(2);
+        assert_eq!(*reset.get(), 2);
+        assert_eq!(*kept.get(), 20);
+    }
+
+    #[test]
+    fn change_count() {
+        let state = Signal::new(0);
+        let changes = create_change_count(state.handle());
+        assert_eq!(*changes.get(), 0);
+
+        state.set(1);
+        state.set(2);
+        assert_eq!(*changes.get(), 2);
+        // Setting an equal value still notifies.
+        state.set(2);
+        assert_eq!(*changes.get(), 3);
+
+        let dedup = Signal::new_dedup(0);
+        let dedup_changes = create_change_count(dedup.handle());
+        dedup.set(1);
+        dedup.set(1);
+        assert_eq!(*dedup_changes.get(), 1);
+    }
+
+    #[test]
+    fn lazy_selector() {
+        let state = Signal::new(2);
+        let calls = Rc::new(Cell::new(0));
+
+        let double = create_lazy_selector(
+            0,
+            cloned!((state, calls) => move || {
+                calls.set(calls.get() + 1);
+                *state.get() * 2
+            }),
+        );
+        assert_eq!(*double.get_untracked(), 0);
+        assert_eq!(calls.get(), 0);
+
+        // The first subscriber sees the initial value and is then re-run with the computed value.
+        let seen = Signal::new(Vec::new());
+        create_effect(cloned!((double, seen) => move || {
+            let value = *double.get();
+            untrack(|| seen.push(value));
+        }));
+        assert_eq!(*seen.get(), vec![0, 4]);
+        assert_eq!(calls.get(), 1);
+
+        state.set(3);
+        assert_eq!(*seen.get(), vec![0, 4, 6]);
+
+        // Like `create_selector`, subscribers are not notified if the value is the same.
+        state.set(3);
+        assert_eq!(*seen.get(), vec![0, 4, 6]);
+    }
+
+    #[test]
+    fn all_true_tracks_every_input() {
+        let a = Signal::new(false);
+        let b = Signal::new(false);
+        let all = all_true(&[a.handle(), b.handle()]);
+        assert!(!*all.get());
+
+        // `b` must still be tracked even though `a` alone decides the result.
+        b.set(true);
+        a.set(true);
+        assert!(*all.get());
+
+        b.set(false);
+        assert!(!*all.get());
+
+        assert!(*all_true(&[]).get());
+    }
+
+    #[test]
+    fn any_true_tracks_every_input() {
+        let a = Signal::new(true);
+        let b = Signal::new(false);
+        let any = any_true(&[a.handle(), b.handle()]);
+        assert!(*any.get());
+
+        let counter = Signal::new(0);
+        create_effect(cloned!((counter, any) => move || {
+            counter.set(*counter.get_untracked() + 1);
+            any.get();
+        }));
+
+        // The result does not change, so dependents are not notified.
+        b.set(true);
+        assert_eq!(*counter.get(), 1);
+
+        a.set(false);
+        b.set(false);
+        assert!(!*any.get());
+        assert_eq!(*counter.get(), 2);
+
+        assert!(!*any_true(&[]).get());
+    }
+
+    #[test]
+    fn cached() {
+        let external = Rc::new(Cell::new(1));
+        let computed = Rc::new(Cell::new(0));
+
+        let (cached, invalidate) = create_cached(cloned!((external, computed) => move || {
+            computed.set(computed.get() + 1);
+            external.get()
+        }));
+        assert_eq!(*cached.get(), 1);
+
+        external.set(2);
+        assert_eq!(*cached.get(), 1);
+        assert_eq!(*cached.get(), 1);
+        assert_eq!(computed.get(), 1);
+
+        let notified = Rc::new(Cell::new(0));
+        create_effect(cloned!((cached, notified) => move || {
+            cached.get();
+            notified.set(notified.get() + 1);
+        }));
+
+        invalidate();
+        assert_eq!(*cached.get(), 2);
+        assert_eq!(computed.get(), 2);
+        assert_eq!(notified.get(), 2);
+    }
+
     #[test]
     fn reducer() {
         enum Msg {
@@ -962,6 +2780,24 @@ mod tests {
         assert_eq!(*state.get(), 2);
     }
 
+    #[test]
+    fn validated_signal() {
+        let (value, error) = create_validated_signal(0, |value| {
+            if *value >= 0 {
+                Ok(())
+            } else {
+                Err("must not be negative".to_string())
+            }
+        });
+        assert_eq!(*error.get(), None);
+
+        value.set(-1);
+        assert_eq!(error.get().as_deref(), Some("must not be negative"));
+
+        value.set(1);
+        assert_eq!(*error.get(), None);
+    }
+
     #[test]
     fn memo_reducer() {
         enum Msg {
@@ -0,0 +1,214 @@
+use std::rc::Rc;
+
+use super::*;
+
+/// The state of a form: the values of its fields and whether they are valid and have been changed.
+///
+/// Fields are added with [`field`](FormState::field), which creates a signal for the value of the
+/// field and a signal for its validation error, like [`create_validated_signal`]. The form keeps
+/// track of the initial value of every field to derive [`is_dirty`](FormState::is_dirty) and to
+/// [`reset`](FormState::reset) the fields.
+///
+/// The derived signals are selectors that are owned by the reactive scope in which the form was
+/// created. Fields can be added at any time and are included in the derived signals right away.
+///
+/// A [`FormState`] is reference counted. Cloning it creates a new handle to the same form.
+///
+/// # Example
+/// A sign up form whose submit button is only enabled when the form is valid and has been changed:
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let form = FormState::new();
+/// let (name, _name_error) = form.field(String::new(), |name: &String| {
+///     if name.is_empty() {
+///         Err("Enter a name".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// });
+/// let (age, age_error) = form.field(18, |age: &u32| {
+///     if *age < 18 {
+///         Err("You must be at least 18".to_string())
+///     } else {
+///         Ok(())
+///     }
+/// });
+/// let can_submit = create_selector(cloned!((form) => move || {
+///     *form.is_valid().get() && *form.is_dirty().get()
+/// }));
+/// assert!(!*can_submit.get());
+///
+/// name.set("Alice".to_string());
+/// assert!(*can_submit.get());
+///
+/// age.set(16);
+/// assert_eq!(age_error.get().as_deref(), Some("You must be at least 18"));
+/// assert!(!*can_submit.get());
+///
+/// form.reset();
+/// assert_eq!(*name.get(), "");
+/// assert!(!*form.is_dirty().get());
+/// ```
+#[derive(Clone)]
+pub struct FormState {
+    fields: Signal<Vec<Rc<dyn FormField>>>,
+    is_valid: ReadSignal<bool>,
+    is_dirty: ReadSignal<bool>,
+}
+
+/// A field of a [`FormState`], with the type of its value erased.
+trait FormField {
+    fn is_valid(&self) -> bool;
+    fn is_dirty(&self) -> bool;
+    fn reset(&self);
+}
+
+struct Field<T: 'static> {
+    value: Signal<T>,
+    initial: Rc<T>,
+    error: ReadSignal<Option<String>>,
+}
+
+impl<T: PartialEq + 'static> FormField for Field<T> {
+    fn is_valid(&self) -> bool {
+        self.error.get().is_none()
+    }
+
+    fn is_dirty(&self) -> bool {
+        *self.value.get() != *self.initial
+    }
+
+    fn reset(&self) {
+        self.value.set_rc(Rc::clone(&self.initial));
+    }
+}
+
+impl FormState {
+    /// Creates a form without any fields.
+    pub fn new() -> Self {
+        let fields: Signal<Vec<Rc<dyn FormField>>> = Signal::new(Vec::new());
+        let is_valid = create_selector(
+            cloned!((fields) => move || fields.get().iter().all(|field| field.is_valid())),
+        );
+        let is_dirty = create_selector(
+            cloned!((fields) => move || fields.get().iter().any(|field| field.is_dirty())),
+        );
+
+        Self {
+            fields,
+            is_valid,
+            is_dirty,
+        }
+    }
+
+    /// Adds a field with the value `initial` to the form. Returns the signal holding the value of
+    /// the field and a [`ReadSignal`] with the error returned by `validate` for the current value,
+    /// like [`create_validated_signal`].
+    ///
+    /// The field is dirty whenever its value is not equal to `initial`.
+    pub fn field<T: PartialEq + 'static>(
+        &self,
+        initial: T,
+        validate: impl Fn(&T) -> Result<(), String> + 'static,
+    ) -> (Signal<T>, ReadSignal<Option<String>>) {
+        let (value, error) = create_validated_signal(initial, validate);
+        self.fields.push(Rc::new(Field {
+            value: value.clone(),
+            initial: value.get_untracked(),
+            error: error.clone(),
+        }));
+
+        (value, error)
+    }
+
+    /// Returns a [`ReadSignal`] that is `true` if none of the fields has a validation error.
+    pub fn is_valid(&self) -> ReadSignal<bool> {
+        self.is_valid.clone()
+    }
+
+    /// Returns a [`ReadSignal`] that is `true` if the value of any field differs from its initial
+    /// value.
+    pub fn is_dirty(&self) -> ReadSignal<bool> {
+        self.is_dirty.clone()
+    }
+
+    /// Sets all the fields back to their initial values. The subscribers are notified once, after
+    /// all the fields have been reset.
+    pub fn reset(&self) {
+        let fields = self.fields.get_untracked();
+        batch(|| {
+            for field in fields.iter() {
+                field.reset();
+            }
+        });
+    }
+}
+
+impl Default for FormState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(clippy::ptr_arg)] // Validators are called with `&T`.
+    fn required(value: &String) -> Result<(), String> {
+        if value.is_empty() {
+            Err("required".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn aggregates_validity_and_dirtiness() {
+        let form = FormState::new();
+        assert!(*form.is_valid().get());
+        assert!(!*form.is_dirty().get());
+
+        let (first, _) = form.field(String::new(), required);
+        let (last, last_error) = form.field("Doe".to_string(), required);
+        // The new invalid field is taken into account right away.
+        assert!(!*form.is_valid().get());
+
+        first.set("John".to_string());
+        assert!(*form.is_valid().get());
+        assert!(*form.is_dirty().get());
+
+        last.set(String::new());
+        assert_eq!(last_error.get().as_deref(), Some("required"));
+        assert!(!*form.is_valid().get());
+
+        // Changing a value back to its initial value makes the field clean again.
+        first.set(String::new());
+        last.set("Doe".to_string());
+        assert!(!*form.is_dirty().get());
+    }
+
+    #[test]
+    fn reset_restores_initial_values() {
+        let form = FormState::new();
+        let (name, _) = form.field("Alice".to_string(), required);
+        let (age, _) = form.field(30, |_| Ok(()));
+
+        let runs = Signal::new(0);
+        create_effect(cloned!((form, runs) => move || {
+            form.is_dirty().get();
+            runs.set(*runs.get_untracked() + 1);
+        }));
+
+        name.set("Bob".to_string());
+        age.set(31);
+        assert!(*form.is_dirty().get());
+        assert_eq!(*runs.get(), 2);
+
+        form.reset();
+        assert_eq!((name.get().as_str(), *age.get()), ("Alice", 30));
+        assert!(!*form.is_dirty().get());
+        assert_eq!(*runs.get(), 3);
+    }
+}
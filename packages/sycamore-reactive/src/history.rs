@@ -0,0 +1,254 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::mem;
+use std::rc::Rc;
+
+use super::*;
+
+/// Undo and redo for a [`Signal`]. Created with [`create_history`].
+///
+/// Every new value of the signal is recorded, no matter where it was set from. Calling
+/// [`undo`](History::undo) or [`redo`](History::redo) sets the signal back to a recorded value
+/// without recording it again.
+///
+/// A [`History`] is reference counted. Cloning it creates a new handle to the same history.
+pub struct History<T: 'static> {
+    signal: Signal<T>,
+    inner: Rc<RefCell<HistoryInner<T>>>,
+    /// Triggered whenever the undo or redo stack changes.
+    changed: Signal<()>,
+}
+
+struct HistoryInner<T> {
+    /// Previous values, the most recent one last.
+    undo: VecDeque<Rc<T>>,
+    /// Values that were undone, the most recently undone one last.
+    redo: Vec<Rc<T>>,
+    /// The value of the signal that was last recorded or restored. Values that are identical to
+    /// it are not recorded again.
+    current: Rc<T>,
+    capacity: usize,
+}
+
+/// Records the values of `signal` so that changes can be undone and redone with the returned
+/// [`History`].
+///
+/// At most `capacity` previous values are kept. Once the limit is reached, the oldest value is
+/// forgotten. Setting the signal after undoing discards the values that could have been redone.
+///
+/// The history stops recording when the current reactive scope is dropped.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let text = Signal::new(String::new());
+/// let history = create_history(text.clone(), 100);
+///
+/// text.set("Hello".to_string());
+/// text.set("Hello World".to_string());
+///
+/// history.undo();
+/// assert_eq!(*text.get(), "Hello");
+///
+/// history.redo();
+/// assert_eq!(*text.get(), "Hello World");
+/// ```
+pub fn create_history<T: Clone + 'static>(signal: Signal<T>, capacity: usize) -> History<T> {
+    let inner = Rc::new(RefCell::new(HistoryInner {
+        undo: VecDeque::new(),
+        redo: Vec::new(),
+        current: signal.get_untracked(),
+        capacity,
+    }));
+    let changed = Signal::new(());
+
+    create_effect({
+        let signal = signal.clone();
+        let inner = Rc::clone(&inner);
+        let changed = changed.clone();
+        move || {
+            let value = signal.get();
+            let mut inner = inner.borrow_mut();
+            if Rc::ptr_eq(&value, &inner.current) {
+                // Initial run or a value restored by `undo` or `redo`.
+                return;
+            }
+            let previous = mem::replace(&mut inner.current, value);
+
+            if inner.capacity > 0 {
+                if inner.undo.len() == inner.capacity {
+                    inner.undo.pop_front();
+                }
+                inner.undo.push_back(previous);
+            }
+            inner.redo.clear();
+            drop(inner);
+            untrack(|| changed.set(()));
+        }
+    });
+
+    History {
+        signal,
+        inner,
+        changed,
+    }
+}
+
+impl<T: Clone + 'static> History<T> {
+    /// Sets the signal back to its previous value. Returns `false` if there is nothing to undo.
+    pub fn undo(&self) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        match inner.undo.pop_back() {
+            Some(previous) => {
+                let current = Rc::clone(&inner.current);
+                inner.redo.push(current);
+                drop(inner);
+                self.restore(&previous);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the signal to the value that was last undone. Returns `false` if there is nothing to
+    /// redo.
+    pub fn redo(&self) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        match inner.redo.pop() {
+            Some(next) => {
+                let current = Rc::clone(&inner.current);
+                inner.undo.push_back(current);
+                drop(inner);
+                self.restore(&next);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn restore(&self, value: &T) {
+        // Inside a batch, the value is updated right away but the subscribers are only notified at
+        // the end. This allows marking the restored value as current before it is recorded.
+        batch(|| {
+            self.signal.set(value.clone());
+            self.inner.borrow_mut().current = self.signal.get_untracked();
+            self.changed.set(());
+        });
+    }
+}
+
+impl<T: 'static> History<T> {
+    /// Returns `true` if there is a value to [`undo`](History::undo) to. If inside a reactive
+    /// scope, the history is added to the scope's dependencies.
+    pub fn can_undo(&self) -> bool {
+        self.changed.get();
+        !self.inner.borrow().undo.is_empty()
+    }
+
+    /// Returns `true` if there is a value to [`redo`](History::redo) to. If inside a reactive
+    /// scope, the history is added to the scope's dependencies.
+    pub fn can_redo(&self) -> bool {
+        self.changed.get();
+        !self.inner.borrow().redo.is_empty()
+    }
+
+    /// Forgets all recorded values. The value of the signal is left unchanged.
+    pub fn clear(&self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.undo.clear();
+        inner.redo.clear();
+        drop(inner);
+        self.changed.set(());
+    }
+}
+
+impl<T: 'static> Clone for History<T> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone(),
+            inner: Rc::clone(&self.inner),
+            changed: self.changed.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undo_redo() {
+        let state = Signal::new(0);
+        let history = create_history(state.clone(), 10);
+        assert!(!history.can_undo());
+        assert!(!history.undo());
+
+        state.set(1);
+        state.set(2);
+        assert!(history.can_undo());
+
+        assert!(history.undo());
+        assert_eq!(*state.get(), 1);
+        assert!(history.undo());
+        assert_eq!(*state.get(), 0);
+        assert!(!history.undo());
+
+        assert!(history.redo());
+        assert_eq!(*state.get(), 1);
+        assert!(history.can_redo());
+
+        // A new edit discards the values that could have been redone.
+        state.set(3);
+        assert!(!history.can_redo());
+        assert!(!history.redo());
+
+        assert!(history.undo());
+        assert_eq!(*state.get(), 1);
+        assert!(history.undo());
+        assert_eq!(*state.get(), 0);
+    }
+
+    #[test]
+    fn capacity() {
+        let state = Signal::new(0);
+        let history = create_history(state.clone(), 2);
+
+        for i in 1..=4 {
+            state.set(i);
+        }
+        assert!(history.undo());
+        assert!(history.undo());
+        assert!(!history.undo());
+        assert_eq!(*state.get(), 2);
+    }
+
+    #[test]
+    fn can_undo_is_reactive() {
+        let state = Signal::new(0);
+        let history = create_history(state.clone(), 10);
+
+        let can_undo = create_memo(cloned!((history) => move || history.can_undo()));
+        assert!(!*can_undo.get());
+
+        state.set(1);
+        assert!(*can_undo.get());
+
+        history.undo();
+        assert!(!*can_undo.get());
+
+        history.clear();
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn undo_inside_batch_is_not_recorded() {
+        let state = Signal::new(0);
+        let history = create_history(state.clone(), 10);
+
+        state.set(1);
+        batch(|| assert!(history.undo()));
+        assert_eq!(*state.get(), 0);
+        assert!(history.can_redo());
+    }
+}
@@ -254,6 +254,56 @@ where
     }
 }
 
+/// Creates a memoized `Vec` by mapping each key in `items` with `f`. The results are cached by key,
+/// meaning that `f` is only called for keys that were not present in the previous `Vec`.
+///
+/// `f` is run inside an untracked scope. The result is only recomputed when `items` changes.
+///
+/// This is the reactive analog of [`map_keyed`] for computed values.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let items = Signal::new(vec![1, 2, 3]);
+/// let squared = create_keyed_memo(items.handle(), |x| x * x);
+/// assert_eq!(*squared.get(), vec![1, 4, 9]);
+///
+/// items.set(vec![1, 2, 3, 4]); // only computes 4 * 4
+/// assert_eq!(*squared.get(), vec![1, 4, 9, 16]);
+/// ```
+pub fn create_keyed_memo<K, V>(
+    items: ReadSignal<Vec<K>>,
+    f: impl Fn(&K) -> V + 'static,
+) -> ReadSignal<Vec<V>>
+where
+    K: Eq + Hash + Clone,
+    V: Clone + 'static,
+{
+    // Previous results used for caching.
+    let mut cache = AHashMap::<K, V>::new();
+
+    create_memo(move || {
+        let items = items.get(); // Subscribe to items.
+        untrack(|| {
+            let mut new_cache = AHashMap::with_capacity(items.len());
+            let values = items
+                .iter()
+                .map(|key| {
+                    let value = cache
+                        .remove(key)
+                        .or_else(|| new_cache.get(key).cloned())
+                        .unwrap_or_else(|| f(key));
+                    new_cache.insert(key.clone(), value.clone());
+                    value
+                })
+                .collect();
+            cache = new_cache;
+            values
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::Cell;
@@ -387,4 +437,46 @@ mod tests {
         a.set(vec![1, 3, 4]);
         assert_eq!(mapped(), vec![1, 5, 4]);
     }
+
+    #[test]
+    fn keyed_memo() {
+        let a = Signal::new(vec![1, 2, 3]);
+        let mapped = create_keyed_memo(a.handle(), |x| *x * 2);
+        assert_eq!(*mapped.get(), vec![2, 4, 6]);
+
+        a.set(vec![3, 2, 2]);
+        assert_eq!(*mapped.get(), vec![6, 4, 4]);
+
+        a.set(vec![]);
+        assert!(mapped.get().is_empty());
+    }
+
+    /// Test that using [`create_keyed_memo`] only computes new keys.
+    #[test]
+    fn keyed_memo_use_previous_computation() {
+        let a = Signal::new(vec![1, 2, 3]);
+        let counter = Rc::new(Cell::new(0));
+        let mapped = create_keyed_memo(a.handle(), {
+            let counter = Rc::clone(&counter);
+            move |x| {
+                counter.set(counter.get() + 1);
+                *x * 2
+            }
+        });
+        assert_eq!(*mapped.get(), vec![2, 4, 6]);
+        assert_eq!(counter.get(), 3);
+
+        a.set(vec![1, 2, 3, 4]);
+        assert_eq!(*mapped.get(), vec![2, 4, 6, 8]);
+        assert_eq!(counter.get(), 4); // only computed for the new item
+
+        a.set(vec![4, 3, 2, 1]);
+        assert_eq!(*mapped.get(), vec![8, 6, 4, 2]);
+        assert_eq!(counter.get(), 4); // reordering does not recompute
+
+        a.set(vec![1]);
+        a.set(vec![1, 2]);
+        assert_eq!(*mapped.get(), vec![2, 4]);
+        assert_eq!(counter.get(), 5); // removed keys are recomputed
+    }
 }
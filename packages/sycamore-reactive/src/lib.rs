@@ -2,22 +2,65 @@
 //!
 //! To learn more, read the section on [Reactivity](https://sycamore-rs.netlify.app/docs/basics/reactivity)
 //! in the Sycamore Book.
+//!
+//! ## Using the reactive system without a UI
+//!
+//! Signals do not depend on a renderer and can be used in tests or in non-UI logic. Reading and
+//! writing a [`Signal`] outside of any effect works like reading and writing a normal value, and
+//! memos and effects created outside of a scope are kept alive for the rest of the program.
+//!
+//! To be able to dispose of effects and run their [`on_cleanup`] callbacks, create them inside
+//! [`create_root`] and drop the returned [`ReactiveScope`] when done:
+//!
+//! ```
+//! use sycamore_reactive::*;
+//!
+//! let count = Signal::new(0);
+//! let log = Signal::new(Vec::new());
+//!
+//! let scope = create_root(cloned!((count, log) => move || {
+//!     create_effect(move || log.push(*count.get()));
+//!     on_cleanup(|| println!("disposed"));
+//! }));
+//! count.set(1);
+//! assert_eq!(*log.get(), vec![0, 1]);
+//!
+//! drop(scope); // Prints "disposed"
+//! count.set(2);
+//! assert_eq!(*log.get(), vec![0, 1]);
+//! ```
 
+mod computed;
 mod context;
 mod effect;
+mod form;
+mod history;
 mod iter;
 mod signal;
+mod store;
+#[cfg(feature = "trace")]
+mod trace;
 
+pub use computed::*;
 pub use context::*;
 pub use effect::*;
+pub use form::*;
+pub use history::*;
 pub use iter::*;
 pub use signal::*;
+pub use store::*;
+#[cfg(feature = "trace")]
+pub use trace::*;
 
 use wasm_bindgen::prelude::*;
 
 /// Creates a new reactive root / scope. Generally, you won't need this method as it is called
 /// automatically in `render`.
 ///
+/// Outside of rendering, this is how effects are disposed of. Dropping the returned
+/// [`ReactiveScope`] destroys all the effects that were created inside `callback` and runs their
+/// [`on_cleanup`] callbacks.
+///
 /// # Example
 /// ```
 /// use sycamore_reactive::*;
@@ -56,6 +99,8 @@ pub fn create_child_scope_in<'a>(
 /// Creates a new reactive root / scope. Generally, you won't need this method as it is called
 /// automatically in `render`.
 ///
+/// See [`create_scope`] for how the returned [`ReactiveScope`] disposes of effects.
+///
 /// # Example
 /// ```
 /// use sycamore_reactive::*;
@@ -85,6 +130,75 @@ pub fn create_root<'a>(callback: impl FnOnce() + 'a) -> ReactiveScope {
     _create_child_scope_in(None, Box::new(callback))
 }
 
+/// Creates a new reactive scope like [`create_scope`] and gives it a name. The name is shown in
+/// the output of [`debug_scope_tree`].
+///
+/// _This API requires the following crate features to be activated: `debug`_
+#[cfg(feature = "debug")]
+#[must_use = "create_scope_named returns the reactive scope of the effects created inside this scope"]
+pub fn create_scope_named<'a>(name: &str, callback: impl FnOnce() + 'a) -> ReactiveScope {
+    _create_child_scope_in(
+        None,
+        Box::new(|| {
+            SCOPES.with(|scopes| {
+                scopes.borrow().last().unwrap_throw().0.borrow_mut().name = Some(name.to_string());
+            });
+            callback();
+        }),
+    )
+}
+
+/// Returns a textual representation of the tree of all the reactive scopes that are currently
+/// alive on this thread. Every line contains the name of a scope (or `<anonymous>` if it was not
+/// created with [`create_scope_named`]), the number of signals and the number of effects that
+/// were created directly inside the scope. Child scopes are indented by two spaces.
+///
+/// Effects run inside their own child scope, so every effect also shows up as an anonymous child
+/// scope.
+///
+/// _This API requires the following crate features to be activated: `debug`_
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let scope = create_scope_named("App", || {
+///     let _state = Signal::new(0);
+/// });
+///
+/// println!("{}", debug_scope_tree());
+/// assert!(debug_scope_tree().contains("App (signals: 1, effects: 0)"));
+/// ```
+#[cfg(feature = "debug")]
+pub fn debug_scope_tree() -> String {
+    fn write_scope(out: &mut String, scope: &ReactiveScopeWeak, depth: usize) {
+        if let Some(scope) = scope.0.upgrade() {
+            let scope = scope.borrow();
+            out.push_str(&format!(
+                "{:indent$}{} (signals: {}, effects: {})\n",
+                "",
+                scope.name.as_deref().unwrap_or("<anonymous>"),
+                scope.signals,
+                scope.effects.len(),
+                indent = depth * 2,
+            ));
+            for child in &scope.children {
+                write_scope(out, child, depth + 1);
+            }
+        }
+    }
+
+    let mut out = String::new();
+    ROOT_SCOPES.with(|roots| {
+        let mut roots = roots.borrow_mut();
+        roots.retain(|root| root.0.strong_count() > 0);
+        for root in roots.iter() {
+            write_scope(&mut out, root, 0);
+        }
+    });
+    out
+}
+
 /// Internal implementation: use dynamic dispatch to reduce code bloat.
 fn _create_child_scope_in<'a>(
     parent: Option<&ReactiveScopeWeak>,
@@ -101,6 +215,23 @@ fn _create_child_scope_in<'a>(
         } else if let Some(parent) = scopes.borrow().last() {
             scope.0.borrow_mut().parent = parent.downgrade();
         }
+        #[cfg(feature = "debug")]
+        {
+            let weak = scope.downgrade();
+            match scope.0.borrow().parent.0.upgrade() {
+                Some(parent) => {
+                    // Effects create a new child scope every time they are re-executed.
+                    let mut parent = parent.borrow_mut();
+                    parent.children.retain(|child| child.0.strong_count() > 0);
+                    parent.children.push(weak);
+                }
+                None => ROOT_SCOPES.with(|roots| {
+                    let mut roots = roots.borrow_mut();
+                    roots.retain(|root| root.0.strong_count() > 0);
+                    roots.push(weak);
+                }),
+            }
+        }
         scopes.borrow_mut().push(scope);
         callback();
 
@@ -139,6 +270,68 @@ macro_rules! cloned {
     };
 }
 
+/// Sets multiple signals inside a single [`batch`]. Subscribers that depend on more than one of
+/// the signals will only be notified once.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let a = Signal::new(0);
+/// let b = Signal::new(String::new());
+/// let c = Signal::new(false);
+///
+/// set_all!(a => 1, b => "x".to_string(), c => true);
+///
+/// assert_eq!(*a.get(), 1);
+/// assert_eq!(*b.get(), "x");
+/// assert_eq!(*c.get(), true);
+/// ```
+#[macro_export]
+macro_rules! set_all {
+    ($($signal:expr => $value:expr),* $(,)?) => {
+        $crate::batch(|| {
+            $( $signal.set($value); )*
+        })
+    };
+}
+
+/// Creates a cached reactive value from an expression. Shorthand for
+/// `create_memo(move || expr)`.
+///
+/// Like [`create_memo`], the expression is re-evaluated whenever any of the signals that are
+/// accessed inside it change, and the result is cached until then. Tracking works exactly the same
+/// as in [`create_memo`].
+///
+/// The expression is evaluated inside a `move` closure. Signals that should still be accessible
+/// afterwards can be cloned first by listing them before a `=>`, like in [`cloned!`].
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let first = Signal::new("John".to_string());
+/// let last = Signal::new("Doe".to_string());
+///
+/// let full = derived!((first, last) => format!("{} {}", first.get(), last.get()));
+/// assert_eq!(*full.get(), "John Doe");
+///
+/// last.set("Smith".to_string());
+/// assert_eq!(*full.get(), "John Smith");
+///
+/// let initials = derived!(full.get().split(' ').map(|s| &s[..1]).collect::<String>());
+/// assert_eq!(*initials.get(), "JS");
+/// ```
+#[macro_export]
+macro_rules! derived {
+    (($($arg:ident),*) => $e:expr) => {
+        $crate::cloned!(($($arg),*) => $crate::create_memo(move || $e))
+    };
+    ($e:expr) => {
+        $crate::create_memo(move || $e)
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::RefCell;
@@ -159,6 +352,73 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn standalone_signals() {
+        // No scope or effect is needed to use signals.
+        let state = Signal::new(1);
+        assert_eq!(*state.get(), 1);
+        state.set(2);
+        assert_eq!(*state.get(), 2);
+
+        let double = create_memo(cloned!((state) => move || *state.get() * 2));
+        state.set(3);
+        assert_eq!(*double.get(), 6);
+    }
+
+    #[test]
+    fn dropping_root_runs_cleanups() {
+        let state = Signal::new(0);
+        let runs = Rc::new(RefCell::new(0));
+        let cleanups = Rc::new(RefCell::new(0));
+
+        let scope = create_root(cloned!((state, runs, cleanups) => move || {
+            create_effect(move || {
+                state.get();
+                *runs.borrow_mut() += 1;
+                on_cleanup(cloned!((cleanups) => move || *cleanups.borrow_mut() += 1));
+            });
+        }));
+        assert_eq!(*runs.borrow(), 1);
+
+        // The cleanup of the previous run is called before re-running the effect.
+        state.set(1);
+        assert_eq!(*runs.borrow(), 2);
+        assert_eq!(*cleanups.borrow(), 1);
+
+        drop(scope);
+        assert_eq!(*cleanups.borrow(), 2);
+
+        state.set(2);
+        assert_eq!(*runs.borrow(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "debug")]
+    fn scope_tree() {
+        let scope = create_scope_named("App", || {
+            let state = Signal::new(0);
+            create_effect(move || {
+                state.get();
+            });
+
+            let child = create_scope_named("Child", || {
+                let _a = Signal::new(1);
+                let _b = Signal::new(2);
+            });
+            on_cleanup(move || drop(child));
+        });
+
+        assert_eq!(
+            debug_scope_tree(),
+            "App (signals: 1, effects: 1)\n  \
+             <anonymous> (signals: 0, effects: 0)\n  \
+             Child (signals: 2, effects: 0)\n"
+        );
+
+        drop(scope);
+        assert_eq!(debug_scope_tree(), "");
+    }
+
     #[test]
     fn cloned() {
         let state = Signal::new(0);
@@ -180,4 +440,55 @@ mod tests {
         // state still accessible outside of the effect
         let _ = state.get();
     }
+
+    #[test]
+    fn set_all() {
+        let a = Signal::new(0);
+        let b = Signal::new(String::new());
+        let c = Signal::new(false);
+
+        let counter = Signal::new(0);
+        create_effect(cloned!((a, b, c, counter) => move || {
+            counter.set(*counter.get_untracked() + 1);
+
+            a.get();
+            b.get();
+            c.get();
+        }));
+        assert_eq!(*counter.get(), 1);
+
+        set_all!(a => 1, b => "x".to_string(), c => true);
+        assert_eq!(*counter.get(), 2); // effect only run once
+
+        assert_eq!(*a.get(), 1);
+        assert_eq!(b.get().as_str(), "x");
+        assert!(*c.get());
+    }
+
+    #[test]
+    fn derived() {
+        let first = Signal::new(1);
+        let second = Signal::new(2);
+
+        let counter = Signal::new(0);
+        let sum = derived!((first, second, counter) => {
+            counter.set(*counter.get_untracked() + 1);
+            *first.get() + *second.get()
+        });
+        assert_eq!(*sum.get(), 3);
+        assert_eq!(*counter.get(), 1);
+
+        first.set(10);
+        assert_eq!(*sum.get(), 12);
+        assert_eq!(*counter.get(), 2);
+
+        // Reading the derived value does not recompute it.
+        assert_eq!(*sum.get(), 12);
+        assert_eq!(*counter.get(), 2);
+
+        let doubled = derived!(*sum.get() * 2);
+        assert_eq!(*doubled.get(), 24);
+        second.set(0);
+        assert_eq!(*doubled.get(), 20);
+    }
 }
@@ -1,7 +1,9 @@
 use std::cell::RefCell;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::mem;
 use std::ops::Deref;
+use std::ptr;
 use std::rc::Rc;
 
 use indexmap::IndexMap;
@@ -29,23 +31,7 @@ impl<T: 'static> ReadSignal<T> {
     /// assert_eq!(*state.get(), 1);
     /// ```
     pub fn get(&self) -> Rc<T> {
-        // If inside an effect, add this signal to dependency list.
-        // If running inside a destructor, do nothing.
-        let _ = LISTENERS.try_with(|listeners| {
-            if let Some(last_context) = listeners.borrow().last() {
-                let signal = Rc::clone(&self.0);
-
-                last_context
-                    .upgrade()
-                    .expect_throw("Running should be valid while inside reactive scope")
-                    .borrow_mut()
-                    .as_mut()
-                    .unwrap_throw()
-                    .dependencies
-                    .insert(Dependency(signal));
-            }
-        });
-
+        track(Rc::clone(&self.0) as Rc<dyn AnySignalInner>);
         self.get_untracked()
     }
 
@@ -74,6 +60,201 @@ impl<T: 'static> ReadSignal<T> {
     pub fn get_untracked(&self) -> Rc<T> {
         Rc::clone(&self.0.borrow().inner)
     }
+
+    /// Registers a hook that is called whenever the signal gets its first subscriber, i.e. when the
+    /// number of subscribers goes from `0` to `1`. This can be used together with
+    /// [`on_last_unsubscribe`](Self::on_last_unsubscribe) to only perform work while somebody is
+    /// listening to the signal.
+    ///
+    /// The hook is called untracked. Multiple hooks can be registered and are called in order.
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let state = Signal::new(0);
+    /// state.on_first_subscribe(|| println!("start polling"));
+    /// state.on_last_unsubscribe(|| println!("stop polling"));
+    ///
+    /// let scope = create_root(cloned!((state) => move || {
+    ///     create_effect(move || {
+    ///         state.get(); // prints "start polling"
+    ///     });
+    /// }));
+    /// drop(scope); // prints "stop polling"
+    /// ```
+    pub fn on_first_subscribe(&self, f: impl Fn() + 'static) {
+        self.0.borrow_mut().on_first_subscribe.push(Rc::new(f));
+    }
+
+    /// Registers a hook that is called whenever the signal loses its last subscriber, i.e. when
+    /// the number of subscribers goes from `1` to `0`.
+    ///
+    /// The hook is called untracked. Multiple hooks can be registered and are called in order.
+    ///
+    /// See [`on_first_subscribe`](Self::on_first_subscribe) for an example.
+    pub fn on_last_unsubscribe(&self, f: impl Fn() + 'static) {
+        self.0.borrow_mut().on_last_unsubscribe.push(Rc::new(f));
+    }
+
+    /// Returns the number of effects and memos that are currently subscribed to this signal.
+    ///
+    /// This is mostly useful in tests for checking that subscriptions are released once a scope is
+    /// disposed.
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let state = Signal::new(0);
+    /// let scope = create_root(cloned!((state) => move || {
+    ///     create_effect(move || {
+    ///         state.get();
+    ///     });
+    /// }));
+    /// assert_eq!(state.subscriber_count(), 1);
+    ///
+    /// drop(scope);
+    /// assert_eq!(state.subscriber_count(), 0);
+    /// ```
+    pub fn subscriber_count(&self) -> usize {
+        self.0.borrow().subscribers.len()
+    }
+
+    /// Get a type-erased [`AnySigRef`] pointing to this signal. This can be used to identify the
+    /// signal without knowing its type.
+    pub fn any_ref(&self) -> AnySigRef {
+        AnySigRef(Rc::clone(&self.0) as Rc<dyn AnySignalInner>)
+    }
+
+    /// Returns a [`SignalId`] that identifies this signal. Clones of a signal share the same
+    /// identity. Unlike the signal itself, the identity does not depend on the value of the signal
+    /// and can therefore be used as a key in a `HashMap` or `BTreeMap`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use sycamore_reactive::*;
+    ///
+    /// let state = Signal::new(0);
+    /// let mut labels = HashMap::new();
+    /// labels.insert(state.identity(), "state");
+    ///
+    /// state.set(1);
+    /// assert_eq!(labels.get(&state.clone().identity()), Some(&"state"));
+    /// ```
+    pub fn identity(&self) -> SignalId {
+        SignalId(self.any_ref().as_ptr())
+    }
+
+    /// Returns a signal that only follows the values of this signal for which `pred` returns
+    /// `true`. When this signal changes to a value that is rejected by `pred`, the returned signal
+    /// keeps the last accepted value. This is useful for ignoring invalid intermediate states.
+    ///
+    /// The returned signal starts with the current value of this signal, even if it is rejected by
+    /// `pred`, since there is no earlier value to fall back on.
+    ///
+    /// The returned signal is updated by an effect that is owned by the current reactive scope.
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let input = Signal::new(String::from("42"));
+    /// let valid = input.filter(|s| s.parse::<u32>().is_ok());
+    ///
+    /// input.set(String::from("4x"));
+    /// assert_eq!(*valid.get(), "42");
+    ///
+    /// input.set(String::from("43"));
+    /// assert_eq!(*valid.get(), "43");
+    /// ```
+    pub fn filter(&self, pred: impl Fn(&T) -> bool + 'static) -> ReadSignal<T>
+    where
+        T: Clone,
+    {
+        let source = self.clone();
+        let output = Signal::new(source.get_untracked().as_ref().clone());
+        create_effect(cloned!((output) => move || {
+            let value = source.get();
+            if pred(&value) {
+                output.set(value.as_ref().clone());
+            }
+        }));
+        output.into_handle()
+    }
+}
+
+impl<T: Clone + 'static> ReadSignal<ReadSignal<T>> {
+    /// Flattens a signal of signals into a signal of the value of the current inner signal.
+    ///
+    /// The returned signal is updated whenever the outer signal changes or the current inner
+    /// signal changes. Once the outer signal switches to a new inner signal, the previous inner
+    /// signal is no longer tracked.
+    ///
+    /// The returned signal is updated by an effect that is owned by the current reactive scope.
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let first = Signal::new("first");
+    /// let second = Signal::new("second");
+    /// let selected = Signal::new(first.handle());
+    ///
+    /// let value = selected.flatten();
+    /// assert_eq!(*value.get(), "first");
+    ///
+    /// selected.set(second.handle());
+    /// assert_eq!(*value.get(), "second");
+    ///
+    /// second.set("updated");
+    /// assert_eq!(*value.get(), "updated");
+    /// ```
+    pub fn flatten(&self) -> ReadSignal<T> {
+        let outer = self.clone();
+        create_memo(move || outer.get().get().as_ref().clone())
+    }
+}
+
+impl<T: Clone + 'static> ReadSignal<Signal<T>> {
+    /// Flattens a signal of signals into a signal of the value of the current inner signal.
+    ///
+    /// This is the same as `flatten` for a `ReadSignal<ReadSignal<T>>`.
+    pub fn flatten(&self) -> ReadSignal<T> {
+        let outer = self.clone();
+        create_memo(move || outer.get().get().as_ref().clone())
+    }
+}
+
+impl<T: Copy + 'static> ReadSignal<T> {
+    /// Returns a copy of the current value of the signal. When called inside a reactive scope,
+    /// calling this will add itself to the scope's dependencies.
+    ///
+    /// This is the same as `*signal.get()` but it does not clone the [`Rc`] holding the value.
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let count = Signal::new(1);
+    /// let double = create_memo(cloned!((count) => move || count.get_copy() * 2));
+    ///
+    /// count.set(2);
+    /// assert_eq!(double.get_copy(), 4);
+    /// ```
+    pub fn get_copy(&self) -> T {
+        track(Rc::clone(&self.0) as Rc<dyn AnySignalInner>);
+        self.get_copy_untracked()
+    }
+
+    /// Returns a copy of the current value of the signal, without tracking this as a dependency
+    /// if inside a reactive context.
+    #[inline]
+    pub fn get_copy_untracked(&self) -> T {
+        *self.0.borrow().inner
+    }
 }
 
 impl<T: 'static> Clone for ReadSignal<T> {
@@ -143,6 +324,13 @@ impl<T: 'static> Signal<T> {
     /// ```
     #[inline]
     pub fn new(initial: T) -> Self {
+        #[cfg(feature = "debug")]
+        let _ = SCOPES.try_with(|scopes| {
+            if let Some(scope) = scopes.borrow().last() {
+                scope.0.borrow_mut().signals += 1;
+            }
+        });
+
         Self {
             handle: ReadSignal(Rc::new(RefCell::new(SignalInner::new(initial)))),
         }
@@ -150,6 +338,9 @@ impl<T: 'static> Signal<T> {
 
     /// Set the current value of the state.
     ///
+    /// If the signal was created with [`Signal::new_dedup`] and `new_value` is equal to the
+    /// current value, this does nothing.
+    ///
     /// This will notify and update any effects and memos that depend on this value.
     ///
     /// # Example
@@ -163,9 +354,75 @@ impl<T: 'static> Signal<T> {
     /// assert_eq!(*state.get(), 1);
     /// ```
     pub fn set(&self, new_value: T) {
-        self.handle.0.borrow_mut().update(new_value);
+        let changed = self.handle.0.borrow_mut().update(new_value);
 
-        self.trigger_subscribers();
+        if changed {
+            self.trigger_subscribers();
+        }
+    }
+
+    /// Set the current value of the state to a value that is already behind an [`Rc`].
+    ///
+    /// The signal stores `new_value` as is, so unlike [`Signal::set`], this does not move the
+    /// value into a new allocation. [`ReadSignal::get`] returns the same [`Rc`] afterwards. This is
+    /// useful for large values that are shared elsewhere.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use sycamore_reactive::*;
+    ///
+    /// let rows = Rc::new(vec![0; 10_000]);
+    /// let state = Signal::new(Vec::new());
+    ///
+    /// state.set_rc(Rc::clone(&rows));
+    /// assert!(Rc::ptr_eq(&state.get(), &rows));
+    /// ```
+    pub fn set_rc(&self, new_value: Rc<T>) {
+        let changed = self.handle.0.borrow_mut().update_rc(new_value);
+
+        if changed {
+            self.trigger_subscribers();
+        }
+    }
+
+    /// Set the current value of the state once the currently running effect has finished.
+    ///
+    /// All the writes that are deferred while an effect is running are applied together inside a
+    /// [`batch`] after the effect has finished running, in the order in which they were deferred.
+    /// Only then are the subscribers notified. This prevents other effects from observing an
+    /// inconsistent state where only some of the signals have been updated.
+    ///
+    /// If not called inside an effect, this is the same as [`Signal::set`].
+    ///
+    /// # Example
+    /// ```
+    /// # use sycamore_reactive::*;
+    /// let source = Signal::new(1);
+    /// let double = Signal::new(0);
+    ///
+    /// create_effect(cloned!((source, double) => move || {
+    ///     double.defer_set(*source.get() * 2);
+    ///     // The write has not been applied yet.
+    ///     assert_ne!(*double.get_untracked(), *source.get_untracked() * 2);
+    /// }));
+    /// assert_eq!(*double.get(), 2);
+    /// ```
+    pub fn defer_set(&self, new_value: T) {
+        let signal = self.clone();
+        let set = move || signal.set(new_value);
+
+        let deferred = DEFERRED.with(|deferred| match deferred.borrow_mut().last_mut() {
+            Some(frame) => {
+                frame.push(Box::new(set) as Box<dyn FnOnce()>);
+                None
+            }
+            None => Some(set),
+        });
+        if let Some(set) = deferred {
+            set();
+        }
     }
 
     /// Get the [`ReadSignal`] associated with this signal.
@@ -187,21 +444,325 @@ impl<T: 'static> Signal<T> {
     /// not be automatically triggered. In the general case, however, it is preferable to use
     /// [`Signal::set`] instead.
     pub fn trigger_subscribers(&self) {
-        // Clone subscribers to prevent modifying list when calling callbacks.
-        let subscribers = self.handle.0.borrow().subscribers.clone();
-
-        // Reverse order of subscribers to trigger outer effects before inner effects.
-        for subscriber in subscribers.values().rev() {
-            // subscriber might have already been destroyed in the case of nested effects
-            if let Some(callback) = subscriber.try_callback() {
-                // Might already be inside a callback, if infinite loop.
-                // Do nothing if infinite loop.
-                if let Ok(mut callback) = callback.try_borrow_mut() {
-                    callback()
+        // Clone subscribers to prevent modifying list when calling callbacks. Effects that are
+        // created while notifying are not called.
+        let mut subscribers: Vec<Callback> = self
+            .handle
+            .0
+            .borrow()
+            .subscribers
+            .values()
+            .cloned()
+            .collect();
+        // Call subscribers in the order the effects were created, so that outer effects run
+        // before inner effects. See the "Execution order" section of [`create_effect`].
+        subscribers.sort_by_key(|subscriber| subscriber.id);
+
+        // If inside a batch, queue up the subscribers instead of calling them.
+        // If running inside a destructor, do not batch.
+        let batched = BATCH
+            .try_with(|batch| {
+                if let Some(batch) = batch.borrow_mut().as_mut() {
+                    for subscriber in &subscribers {
+                        let _batched =
+                            batch
+                                .entry(subscriber.as_ptr())
+                                .or_insert_with(|| BatchedCallback {
+                                    callback: subscriber.clone(),
+                                    #[cfg(feature = "debug")]
+                                    triggered_by: Vec::new(),
+                                });
+                        #[cfg(feature = "debug")]
+                        _batched.triggered_by.push(self.any_ref());
+                    }
+                    true
+                } else {
+                    false
                 }
+            })
+            .unwrap_or(false);
+        if batched {
+            return;
+        }
+
+        // Record which signal caused the subscribers to run. Skipped if the thread local has
+        // already been destroyed, e.g. when running inside a destructor.
+        #[cfg(feature = "debug")]
+        let _ = TRIGGERED.try_with(|triggered| triggered.borrow_mut().push(vec![self.any_ref()]));
+
+        #[cfg(feature = "trace")]
+        let timer = trace::Timer::start();
+
+        for subscriber in &subscribers {
+            subscriber.call();
+        }
+
+        #[cfg(feature = "trace")]
+        timer.finish(subscribers.len());
+
+        #[cfg(feature = "debug")]
+        let _ = TRIGGERED.try_with(|triggered| triggered.borrow_mut().pop());
+    }
+}
+
+impl<T: PartialEq + 'static> Signal<T> {
+    /// Creates a new signal that ignores writes of a value that is equal to its current value.
+    ///
+    /// Unlike [`create_selector`], which compares the values of a derived signal, the comparison
+    /// happens when the signal is written to with [`Signal::set`]. If the new value is equal to
+    /// the current one, it is dropped right away: the [`Rc`] returned by [`ReadSignal::get`] stays
+    /// the same and the subscribers are not notified. This avoids redundant work downstream when
+    /// the same value is set repeatedly. It also means that [`Rc::ptr_eq`] can be used to check
+    /// whether the value has changed.
+    ///
+    /// # Example
+    /// ```
+    /// use std::rc::Rc;
+    ///
+    /// use sycamore_reactive::*;
+    ///
+    /// let state = Signal::new_dedup(1);
+    /// let runs = Signal::new(0);
+    /// create_effect(cloned!((state, runs) => move || {
+    ///     state.get();
+    ///     runs.set(*runs.get_untracked() + 1);
+    /// }));
+    ///
+    /// let before = state.get_untracked();
+    /// state.set(1);
+    /// assert!(Rc::ptr_eq(&before, &state.get_untracked()));
+    /// assert_eq!(*runs.get(), 1);
+    ///
+    /// state.set(2);
+    /// assert_eq!(*runs.get(), 2);
+    /// ```
+    pub fn new_dedup(initial: T) -> Self {
+        let signal = Self::new(initial);
+        signal.handle.0.borrow_mut().eq = Some(T::eq);
+        signal
+    }
+}
+
+impl<T: Default + Clone + 'static> Signal<T> {
+    /// Takes the current value out of the signal, leaving [`Default::default()`] in its place, and
+    /// notifies the subscribers. This is like [`std::mem::take`] for signals.
+    ///
+    /// The value is only cloned if it is still referenced elsewhere, e.g. by an [`Rc`] returned
+    /// from [`ReadSignal::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let queue = Signal::new(vec![1, 2, 3]);
+    /// let drained = queue.take();
+    ///
+    /// assert_eq!(drained, vec![1, 2, 3]);
+    /// assert!(queue.get().is_empty());
+    /// ```
+    pub fn take(&self) -> T {
+        let old = mem::replace(&mut self.handle.0.borrow_mut().inner, Rc::new(T::default()));
+
+        self.trigger_subscribers();
+
+        Rc::try_unwrap(old).unwrap_or_else(|old| old.as_ref().clone())
+    }
+}
+
+impl<T: 'static> ReadSignal<Vec<T>> {
+    /// Returns the number of elements in the vector. The signal is tracked.
+    pub fn len(&self) -> usize {
+        self.get().len()
+    }
+
+    /// Returns `true` if the vector contains no elements. The signal is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.get().is_empty()
+    }
+
+    /// Maps every element of the vector with `f` and collects the results. The signal is tracked.
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let names = Signal::new(vec!["a", "b"]);
+    /// assert_eq!(names.iter_map(|name| name.to_uppercase()), vec!["A", "B"]);
+    /// ```
+    pub fn iter_map<U>(&self, f: impl FnMut(&T) -> U) -> Vec<U> {
+        self.get().iter().map(f).collect()
+    }
+}
+
+impl<T: Clone + 'static> ReadSignal<Vec<T>> {
+    /// Returns an owned copy of the vector. The signal is tracked.
+    ///
+    /// This is a shortcut for `(*signal.get()).clone()`.
+    pub fn cloned_vec(&self) -> Vec<T> {
+        self.get().as_ref().clone()
+    }
+}
+
+impl<T: Clone + 'static> Signal<Vec<T>> {
+    /// Appends `item` to the end of the vector and notifies the subscribers.
+    ///
+    /// The vector is updated in place. It is only cloned if it is still referenced elsewhere, e.g.
+    /// by an [`Rc`] returned from [`ReadSignal::get`].
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let items = Signal::new(vec![1, 2]);
+    /// items.push(3);
+    /// assert_eq!(items.cloned_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn push(&self, item: T) {
+        Rc::make_mut(&mut self.handle.0.borrow_mut().inner).push(item);
+
+        self.trigger_subscribers();
+    }
+}
+
+impl<T: Clone + 'static> Signal<Option<T>> {
+    /// Returns the value of the signal, first setting it to `Some(f())` if it is `None`. This is
+    /// the reactive analog of [`Option::get_or_insert_with`].
+    ///
+    /// `f` is only called if the signal is `None`, in which case the subscribers are notified. The
+    /// returned [`Rc`] holds a clone of the value. If inside a reactive scope, the signal is added
+    /// to the scope's dependencies, like with [`get`](ReadSignal::get).
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore_reactive::*;
+    ///
+    /// let cache = Signal::new(None);
+    /// assert_eq!(*cache.get_or_insert_with(|| "computed"), "computed");
+    /// assert_eq!(*cache.get(), Some("computed"));
+    /// ```
+    pub fn get_or_insert_with(&self, f: impl FnOnce() -> T) -> Rc<T> {
+        let value = match self.get().as_ref() {
+            Some(value) => value.clone(),
+            None => {
+                let value = f();
+                self.set(Some(value.clone()));
+                value
             }
+        };
+        Rc::new(value)
+    }
+}
+
+/// Reactive helpers for signals holding an [`Option`].
+///
+/// Every helper returns a selector (see [`create_selector`]) that is derived from the signal, so
+/// the result is cached and only notifies its subscribers when it actually changes. The helpers
+/// work on both [`Signal`] and [`ReadSignal`].
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// let user: Signal<Option<String>> = Signal::new(None);
+/// let logged_in = user.is_some();
+/// let name_len = user.map_reactive(|name| name.len());
+/// let name = user.unwrap_or("Guest".to_string());
+/// assert_eq!((*logged_in.get(), *name_len.get()), (false, None));
+/// assert_eq!(*name.get(), "Guest");
+///
+/// user.set(Some("Alice".to_string()));
+/// assert_eq!((*logged_in.get(), *name_len.get()), (true, Some(5)));
+/// assert_eq!(*name.get(), "Alice");
+/// ```
+pub trait SignalOptionExt<T: 'static> {
+    /// Returns a signal that is `true` while the value is `Some`.
+    fn is_some(&self) -> ReadSignal<bool>;
+
+    /// Returns a signal holding the result of `f` applied to the value, or `None` if the value is
+    /// `None`. This is the reactive analog of [`Option::map`].
+    fn map_reactive<U, F>(&self, f: F) -> ReadSignal<Option<U>>
+    where
+        U: PartialEq + 'static,
+        F: Fn(&T) -> U + 'static;
+
+    /// Returns a signal holding the value, or `default` if the value is `None`. This is the
+    /// reactive analog of [`Option::unwrap_or`].
+    fn unwrap_or(&self, default: T) -> ReadSignal<T>
+    where
+        T: Clone + PartialEq;
+}
+
+impl<T: 'static> SignalOptionExt<T> for ReadSignal<Option<T>> {
+    fn is_some(&self) -> ReadSignal<bool> {
+        let signal = self.clone();
+        create_selector(move || signal.get().is_some())
+    }
+
+    fn map_reactive<U, F>(&self, f: F) -> ReadSignal<Option<U>>
+    where
+        U: PartialEq + 'static,
+        F: Fn(&T) -> U + 'static,
+    {
+        let signal = self.clone();
+        create_selector(move || signal.get().as_ref().as_ref().map(&f))
+    }
+
+    fn unwrap_or(&self, default: T) -> ReadSignal<T>
+    where
+        T: Clone + PartialEq,
+    {
+        let signal = self.clone();
+        create_selector(move || {
+            signal
+                .get()
+                .as_ref()
+                .clone()
+                .unwrap_or_else(|| default.clone())
+        })
+    }
+}
+
+impl<T: 'static> Signal<RefCell<T>> {
+    /// Creates a new signal that wraps an existing shared [`RefCell`]. This is useful for interop
+    /// with non-reactive code that already holds an `Rc<RefCell<T>>`.
+    ///
+    /// Mutations made through the [`RefCell`] are not tracked automatically. Call
+    /// [`Signal::notify`] afterwards to update the subscribers. Note that calling [`Signal::set`]
+    /// replaces the shared cell with a new one, after which it is no longer shared with the
+    /// external code.
+    ///
+    /// # Example
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// use sycamore_reactive::*;
+    ///
+    /// // State that is owned by legacy, non-reactive code.
+    /// let shared = Rc::new(RefCell::new(vec![1, 2]));
+    ///
+    /// let state = Signal::from_shared(Rc::clone(&shared));
+    /// let len = create_memo(cloned!((state) => move || state.get().borrow().len()));
+    /// assert_eq!(*len.get(), 2);
+    ///
+    /// // The legacy code mutates the state...
+    /// shared.borrow_mut().push(3);
+    /// assert_eq!(*len.get(), 2);
+    /// // ...and notifies the subscribers.
+    /// state.notify();
+    /// assert_eq!(*len.get(), 3);
+    /// ```
+    pub fn from_shared(shared: Rc<RefCell<T>>) -> Self {
+        Self {
+            handle: ReadSignal(Rc::new(RefCell::new(SignalInner::from_rc(shared)))),
         }
     }
+
+    /// Notifies the subscribers that the value inside the [`RefCell`] has been mutated. This is
+    /// the same as [`Signal::trigger_subscribers`].
+    pub fn notify(&self) {
+        self.trigger_subscribers();
+    }
 }
 
 impl<T: Default> Default for Signal<T> {
@@ -226,6 +787,8 @@ impl<T: 'static> Clone for Signal<T> {
     }
 }
 
+/// Signals are compared by value. Use [`ReadSignal::identity`] to compare signals by identity
+/// instead.
 impl<T: PartialEq> PartialEq for Signal<T> {
     fn eq(&self, other: &Signal<T>) -> bool {
         self.get_untracked().eq(&other.get_untracked())
@@ -234,6 +797,9 @@ impl<T: PartialEq> PartialEq for Signal<T> {
 
 impl<T: Eq> Eq for Signal<T> {}
 
+/// Signals are hashed by value. Because the hash changes when the value of the signal changes, a
+/// signal with a mutable value must not be used as a key in a `HashMap` or `HashSet`. Wrap it in
+/// [`ByRef`] or use [`ReadSignal::identity`] instead.
 impl<T: Hash> Hash for Signal<T> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.get_untracked().hash(state);
@@ -268,16 +834,154 @@ impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Signal<T> {
     }
 }
 
+/// A type-erased reference to a [`Signal`] or [`ReadSignal`], regardless of type param `T`.
+///
+/// Two [`AnySigRef`]s are equal if they point to the same signal. Use [`ReadSignal::any_ref`] to
+/// obtain one.
+#[derive(Clone)]
+pub struct AnySigRef(Rc<dyn AnySignalInner>);
+
+impl AnySigRef {
+    /// Returns `true` if this [`AnySigRef`] points to `signal`.
+    pub fn is<T: 'static>(&self, signal: &ReadSignal<T>) -> bool {
+        *self == signal.any_ref()
+    }
+
+    /// Returns the address of the signal this [`AnySigRef`] points to.
+    pub fn as_ptr(&self) -> *const () {
+        Rc::as_ptr(&self.0).cast()
+    }
+
+    /// Adds the signal to the dependencies of the current reactive scope without reading its value,
+    /// as if [`get`](ReadSignal::get) had been called.
+    pub fn track(&self) {
+        track(Rc::clone(&self.0));
+    }
+}
+
+/// Adds `signal` to the dependencies of the current effect, if any.
+fn track(signal: Rc<dyn AnySignalInner>) {
+    // If running inside a destructor, do nothing.
+    let _ = LISTENERS.try_with(|listeners| {
+        if let Some(last_context) = listeners.borrow().last() {
+            last_context
+                .upgrade()
+                .expect_throw("Running should be valid while inside reactive scope")
+                .borrow_mut()
+                .as_mut()
+                .unwrap_throw()
+                .dependencies
+                .insert(Dependency(signal));
+        }
+    });
+}
+
+impl PartialEq for AnySigRef {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.as_ptr(), other.as_ptr())
+    }
+}
+
+impl Eq for AnySigRef {}
+
+impl Hash for AnySigRef {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ptr().hash(state);
+    }
+}
+
+impl fmt::Debug for AnySigRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnySigRef").field(&self.as_ptr()).finish()
+    }
+}
+
+/// A stable identity of a signal, obtained with [`ReadSignal::identity`].
+///
+/// Two [`SignalId`]s are equal if they belong to the same signal. A [`SignalId`] does not keep the
+/// signal alive. Once the signal is dropped, its identity may be reused by a new signal.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct SignalId(pub(super) *const ());
+
+/// A wrapper around a [`Signal`] or [`ReadSignal`] that is compared and hashed by identity instead
+/// of by value.
+///
+/// Unlike the signal itself, a [`ByRef`] keeps the same hash when the value of the signal changes,
+/// so it can safely be stored in a `HashSet` or used as a key in a `HashMap`. Two [`ByRef`]s are
+/// equal if they wrap clones of the same signal, even if the signals have equal values. The
+/// wrapped signal is accessible through [`Deref`].
+///
+/// # Example
+/// ```
+/// use std::collections::HashSet;
+///
+/// use sycamore_reactive::*;
+///
+/// let state = Signal::new(0);
+/// let mut watched = HashSet::new();
+/// watched.insert(ByRef(state.clone()));
+///
+/// state.set(1);
+/// assert!(watched.contains(&ByRef(state.clone())));
+/// assert!(!watched.contains(&ByRef(Signal::new(1))));
+/// ```
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ByRef<S>(pub S);
+
+impl<S> Deref for ByRef<S> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! impl_by_ref {
+    ($signal:ident) => {
+        impl<T: 'static> PartialEq for ByRef<$signal<T>> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0.identity() == other.0.identity()
+            }
+        }
+
+        impl<T: 'static> Eq for ByRef<$signal<T>> {}
+
+        impl<T: 'static> Hash for ByRef<$signal<T>> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.any_ref().as_ptr().hash(state);
+            }
+        }
+    };
+}
+
+impl_by_ref!(Signal);
+impl_by_ref!(ReadSignal);
+
 pub(super) struct SignalInner<T> {
     inner: Rc<T>,
     subscribers: IndexMap<CallbackPtr, Callback>,
+    /// Hooks that are called when the number of subscribers goes from `0` to `1`.
+    on_first_subscribe: Vec<Rc<dyn Fn()>>,
+    /// Hooks that are called when the number of subscribers goes from `1` to `0`.
+    on_last_unsubscribe: Vec<Rc<dyn Fn()>>,
+    /// If set, updates to a value that is equal to the current value are dropped. See
+    /// [`Signal::new_dedup`].
+    eq: Option<fn(&T, &T) -> bool>,
 }
 
 impl<T> SignalInner<T> {
     fn new(value: T) -> Self {
+        Self::from_rc(Rc::new(value))
+    }
+
+    /// Creates a new [`SignalInner`] that shares `inner` instead of allocating a new [`Rc`].
+    fn from_rc(inner: Rc<T>) -> Self {
         Self {
-            inner: Rc::new(value),
+            inner,
             subscribers: IndexMap::new(),
+            on_first_subscribe: Vec::new(),
+            on_last_unsubscribe: Vec::new(),
+            eq: None,
         }
     }
 
@@ -294,8 +998,27 @@ impl<T> SignalInner<T> {
 
     /// Updates the inner value. This does **NOT** call the subscribers.
     /// You will have to do so manually with `trigger_subscribers`.
-    fn update(&mut self, new_value: T) {
+    ///
+    /// Returns `false` if the value was not updated because it is equal to the current value.
+    fn update(&mut self, new_value: T) -> bool {
+        if let Some(eq) = self.eq {
+            if eq(&self.inner, &new_value) {
+                return false;
+            }
+        }
         self.inner = Rc::new(new_value);
+        true
+    }
+
+    /// Like [`update`](Self::update) but stores `new_value` without allocating a new [`Rc`].
+    fn update_rc(&mut self, new_value: Rc<T>) -> bool {
+        if let Some(eq) = self.eq {
+            if eq(&self.inner, &new_value) {
+                return false;
+            }
+        }
+        self.inner = new_value;
+        true
     }
 }
 
@@ -309,16 +1032,43 @@ pub(super) trait AnySignalInner {
 
 impl<T> AnySignalInner for RefCell<SignalInner<T>> {
     fn subscribe(&self, handler: Callback) {
-        self.borrow_mut().subscribe(handler);
+        let hooks = {
+            let mut inner = self.borrow_mut();
+            let was_empty = inner.subscribers.is_empty();
+            inner.subscribe(handler);
+            if was_empty && !inner.subscribers.is_empty() {
+                inner.on_first_subscribe.clone()
+            } else {
+                Vec::new()
+            }
+        };
+        // Call the hooks after the borrow is released because the hooks might access the signal.
+        for hook in hooks {
+            untrack(|| hook());
+        }
     }
 
     fn unsubscribe(&self, handler: CallbackPtr) {
-        self.borrow_mut().unsubscribe(handler);
+        let hooks = {
+            let mut inner = self.borrow_mut();
+            let was_empty = inner.subscribers.is_empty();
+            inner.unsubscribe(handler);
+            if !was_empty && inner.subscribers.is_empty() {
+                inner.on_last_unsubscribe.clone()
+            } else {
+                Vec::new()
+            }
+        };
+        for hook in hooks {
+            untrack(|| hook());
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+
     use super::*;
 
     #[test]
@@ -352,4 +1102,368 @@ mod tests {
         state.set(1);
         assert_eq!(*readonly.get(), 1);
     }
+
+    #[test]
+    fn subscription_hooks() {
+        let state = Signal::new(0);
+        let first = Signal::new(0);
+        let last = Signal::new(0);
+        state.on_first_subscribe(cloned!((first) => move || first.set(*first.get() + 1)));
+        state.on_last_unsubscribe(cloned!((last) => move || last.set(*last.get() + 1)));
+
+        let scope1 = create_root(cloned!((state) => move || {
+            create_effect(move || {
+                state.get();
+            });
+        }));
+        assert_eq!(*first.get(), 1);
+        assert_eq!(*last.get(), 0);
+
+        // Second subscriber does not trigger the hook.
+        let scope2 = create_root(cloned!((state) => move || {
+            create_effect(move || {
+                state.get();
+            });
+        }));
+        assert_eq!(*first.get(), 1);
+
+        // Re-running an effect does not resubscribe.
+        state.set(1);
+        assert_eq!(*first.get(), 1);
+        assert_eq!(*last.get(), 0);
+
+        drop(scope1);
+        assert_eq!(*last.get(), 0);
+        drop(scope2);
+        assert_eq!(*last.get(), 1);
+
+        // Subscribing again triggers the hook again.
+        let _scope3 = create_root(cloned!((state) => move || {
+            create_effect(move || {
+                state.get();
+            });
+        }));
+        assert_eq!(*first.get(), 2);
+        assert_eq!(*last.get(), 1);
+    }
+
+    #[test]
+    fn identity() {
+        let state = Signal::new(0);
+        let other = Signal::new(0);
+        assert_eq!(state.identity(), state.clone().identity());
+        assert_eq!(state.identity(), state.handle().identity());
+        assert_ne!(state.identity(), other.identity());
+
+        let id = state.identity();
+        state.set(1);
+        assert_eq!(state.identity(), id);
+    }
+
+    #[test]
+    fn value_hash_breaks_map_lookups_after_set() {
+        use std::collections::HashSet;
+
+        let state = Signal::new(0);
+
+        let mut by_value = HashSet::new();
+        by_value.insert(state.clone());
+        let mut by_identity = HashSet::new();
+        by_identity.insert(state.identity());
+
+        state.set(1);
+        // The hash of the signal changed so it can no longer be found.
+        assert!(!by_value.contains(&state));
+        assert!(by_identity.contains(&state.identity()));
+    }
+
+    #[test]
+    // `ByRef` only hashes the address of the signal, which never changes.
+    #[allow(clippy::mutable_key_type)]
+    fn by_ref_membership_is_stable_across_set() {
+        use std::collections::HashSet;
+
+        let state = Signal::new(0);
+        let other = Signal::new(0);
+
+        let mut set = HashSet::new();
+        set.insert(ByRef(state.clone()));
+        set.insert(ByRef(other.clone()));
+        // Signals with equal values are still distinct.
+        assert_eq!(set.len(), 2);
+
+        state.set(1);
+        assert!(set.contains(&ByRef(state.clone())));
+        assert!(set.contains(&ByRef(other.clone())));
+        assert!(!set.contains(&ByRef(Signal::new(1))));
+
+        let mut handles = HashSet::new();
+        handles.insert(ByRef(state.handle()));
+        state.set(2);
+        assert!(handles.contains(&ByRef(state.handle())));
+    }
+
+    #[test]
+    fn flatten_switches_inner_signal() {
+        let first = Signal::new(1);
+        let second = Signal::new(10);
+        let selected = Signal::new(first.handle());
+        let flat = selected.flatten();
+
+        let runs = Signal::new(0);
+        create_effect(cloned!((flat, runs) => move || {
+            flat.get();
+            runs.set(*runs.get_untracked() + 1);
+        }));
+        assert_eq!(*flat.get(), 1);
+        assert_eq!(*runs.get(), 1);
+
+        first.set(2);
+        assert_eq!(*flat.get(), 2);
+        assert_eq!(*runs.get(), 2);
+
+        selected.set(second.handle());
+        assert_eq!(*flat.get(), 10);
+        assert_eq!(*runs.get(), 3);
+
+        // The previous inner signal is no longer tracked.
+        first.set(3);
+        assert_eq!(*flat.get(), 10);
+        assert_eq!(*runs.get(), 3);
+
+        second.set(11);
+        assert_eq!(*flat.get(), 11);
+        assert_eq!(*runs.get(), 4);
+    }
+
+    #[test]
+    fn flatten_signal_of_signals() {
+        let inner = Signal::new(1);
+        let outer = Signal::new(inner.clone());
+        let flat = outer.flatten();
+
+        inner.set(2);
+        assert_eq!(*flat.get(), 2);
+
+        outer.set(Signal::new(3));
+        assert_eq!(*flat.get(), 3);
+    }
+
+    #[test]
+    fn set_rc() {
+        let state = Signal::new(String::new());
+        let runs = Signal::new(0);
+        create_effect(cloned!((state, runs) => move || {
+            state.get();
+            runs.set(*runs.get_untracked() + 1);
+        }));
+
+        let shared = Rc::new(String::from("shared"));
+        state.set_rc(Rc::clone(&shared));
+        assert!(Rc::ptr_eq(&state.get(), &shared));
+        assert_eq!(*runs.get(), 2);
+    }
+
+    #[test]
+    fn dedup_keeps_rc_for_equal_value() {
+        let state = Signal::new_dedup(vec![1, 2]);
+        let runs = Signal::new(0);
+        create_effect(cloned!((state, runs) => move || {
+            state.get();
+            runs.set(*runs.get_untracked() + 1);
+        }));
+
+        let before = state.get();
+        state.set(vec![1, 2]);
+        assert!(Rc::ptr_eq(&before, &state.get()));
+        assert_eq!(*runs.get(), 1);
+
+        state.set(vec![3]);
+        assert!(!Rc::ptr_eq(&before, &state.get()));
+        assert_eq!(*state.get(), [3]);
+        assert_eq!(*runs.get(), 2);
+
+        // Signals created with `new` are always updated.
+        let state = Signal::new(1);
+        let before = state.get();
+        state.set(1);
+        assert!(!Rc::ptr_eq(&before, &state.get()));
+    }
+
+    #[test]
+    fn filter() {
+        let state = Signal::new(1);
+        let positive = state.filter(|n| *n >= 0);
+
+        state.set(-1);
+        assert_eq!(*positive.get(), 1);
+
+        state.set(2);
+        assert_eq!(*positive.get(), 2);
+
+        state.set(-2);
+        assert_eq!(*positive.get(), 2);
+    }
+
+    #[test]
+    fn filter_keeps_rejected_initial_value() {
+        let state = Signal::new(-1);
+        let positive = state.filter(|n| *n >= 0);
+        assert_eq!(*positive.get(), -1);
+
+        state.set(-2);
+        assert_eq!(*positive.get(), -1);
+
+        state.set(3);
+        assert_eq!(*positive.get(), 3);
+    }
+
+    #[test]
+    fn take() {
+        let buffer = Signal::new(vec![1, 2, 3]);
+
+        let runs = Signal::new(0);
+        create_effect(cloned!((buffer, runs) => move || {
+            buffer.get();
+            runs.set(*runs.get_untracked() + 1);
+        }));
+
+        let taken = buffer.take();
+        assert_eq!(taken, vec![1, 2, 3]);
+        assert!(buffer.get().is_empty());
+        assert_eq!(*runs.get(), 2);
+
+        // Still works when the old value is referenced elsewhere.
+        buffer.set(vec![4]);
+        let held = buffer.get();
+        assert_eq!(buffer.take(), vec![4]);
+        assert_eq!(*held, vec![4]);
+        assert!(buffer.get().is_empty());
+    }
+
+    #[test]
+    fn get_or_insert_with() {
+        let cache = Signal::new(None);
+        let calls = Cell::new(0);
+        let compute = || {
+            calls.set(calls.get() + 1);
+            42
+        };
+
+        let notified = Signal::new(0);
+        create_effect(cloned!((cache, notified) => move || {
+            cache.get();
+            notified.set(*notified.get_untracked() + 1);
+        }));
+
+        assert_eq!(*cache.get_or_insert_with(compute), 42);
+        assert_eq!(*cache.get_or_insert_with(compute), 42);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(*cache.get(), Some(42));
+        assert_eq!(*notified.get(), 2);
+    }
+
+    #[test]
+    fn get_copy() {
+        let state = Signal::new(1);
+        let runs = Signal::new(0);
+        let sum = Signal::new(0);
+
+        create_effect(cloned!((state, runs, sum) => move || {
+            sum.set(sum.get_copy_untracked() + state.get_copy());
+            runs.set(runs.get_copy_untracked() + 1);
+        }));
+        assert_eq!((sum.get_copy(), runs.get_copy()), (1, 1));
+
+        state.set(2);
+        assert_eq!((sum.get_copy(), runs.get_copy()), (3, 2));
+        assert_eq!(state.get_copy_untracked(), 2);
+    }
+
+    #[test]
+    fn option_ext() {
+        let state = Signal::new(Some(2));
+        let is_some = state.is_some();
+        let doubled = state.map_reactive(|value| value * 2);
+        let or_zero = state.handle().unwrap_or(0);
+
+        let is_some_runs = Signal::new(0);
+        create_effect(cloned!((is_some, is_some_runs) => move || {
+            is_some.get();
+            is_some_runs.set(*is_some_runs.get_untracked() + 1);
+        }));
+        assert_eq!(
+            (*is_some.get(), *doubled.get(), *or_zero.get()),
+            (true, Some(4), 2)
+        );
+
+        state.set(Some(3));
+        assert_eq!(
+            (*is_some.get(), *doubled.get(), *or_zero.get()),
+            (true, Some(6), 3)
+        );
+        // `is_some` did not change.
+        assert_eq!(*is_some_runs.get(), 1);
+
+        state.set(None);
+        assert_eq!(
+            (*is_some.get(), *doubled.get(), *or_zero.get()),
+            (false, None, 0)
+        );
+        assert_eq!(*is_some_runs.get(), 2);
+
+        state.set(Some(1));
+        assert_eq!(
+            (*is_some.get(), *doubled.get(), *or_zero.get()),
+            (true, Some(2), 1)
+        );
+        assert_eq!(*is_some_runs.get(), 3);
+    }
+
+    #[test]
+    fn vec_push() {
+        let items = Signal::new(Vec::new());
+
+        let runs = Signal::new(0);
+        create_effect(cloned!((items, runs) => move || {
+            items.len();
+            runs.set(*runs.get_untracked() + 1);
+        }));
+        assert!(items.is_empty());
+        assert_eq!(*runs.get(), 1);
+
+        items.push("a");
+        assert_eq!(items.len(), 1);
+        assert_eq!(*runs.get(), 2);
+
+        // Does not modify a value that is still referenced elsewhere.
+        let held = items.get();
+        items.push("b");
+        assert_eq!(*held, vec!["a"]);
+        assert_eq!(items.cloned_vec(), vec!["a", "b"]);
+        assert_eq!(*runs.get(), 3);
+
+        assert_eq!(items.iter_map(|item| item.len()), vec![1, 1]);
+    }
+
+    #[test]
+    fn from_shared() {
+        let shared = Rc::new(RefCell::new(0));
+        let state = Signal::from_shared(Rc::clone(&shared));
+        assert!(Rc::ptr_eq(&state.get(), &shared));
+
+        let counter = Signal::new(0);
+        create_effect(cloned!((state, counter) => move || {
+            state.get();
+            counter.set(*counter.get_untracked() + 1);
+        }));
+        assert_eq!(*counter.get(), 1);
+
+        *shared.borrow_mut() = 1;
+        assert_eq!(*counter.get(), 1);
+        assert_eq!(*state.get().borrow(), 1);
+
+        state.notify();
+        assert_eq!(*counter.get(), 2);
+    }
 }
@@ -0,0 +1,299 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use ahash::AHashMap;
+
+use super::*;
+
+/// Nested reactive state with fine-grained tracking of individual fields. Created with
+/// [`create_store`].
+///
+/// Unlike a [`Signal`], which notifies all its subscribers whenever any part of its value
+/// changes, a [`Store`] tracks accesses by _path_. A path is a `.`-separated list of field names,
+/// such as `"user.name"`. The empty path `""` refers to the whole store.
+///
+/// Updating a path notifies the subscribers of that path, of all its parents (e.g. `"user"` when
+/// updating `"user.name"`) and of all its children (e.g. `"user.name.first"`). Subscribers of
+/// sibling paths (e.g. `"user.email"`) are not notified.
+///
+/// It is up to the caller to make sure that the path matches the fields that are accessed. The
+/// [`store_get!`](crate::store_get) and [`store_set!`](crate::store_set) macros derive the path
+/// from the field accesses so that they cannot get out of sync.
+///
+/// A [`Store`] is reference counted. Cloning it creates a new handle to the same state.
+pub struct Store<T: 'static>(Rc<StoreInner<T>>);
+
+struct StoreInner<T> {
+    value: RefCell<T>,
+    /// A trigger for every path that has been accessed. Tracking a path subscribes to its trigger.
+    paths: RefCell<AHashMap<String, Signal<()>>>,
+}
+
+/// Creates a new [`Store`] with the given initial state.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// #[derive(Clone)]
+/// struct User {
+///     name: String,
+///     email: String,
+/// }
+///
+/// let store = create_store(User {
+///     name: "John".to_string(),
+///     email: "john@example.com".to_string(),
+/// });
+///
+/// let name_changes = Signal::new(0);
+/// create_effect(cloned!((store, name_changes) => move || {
+///     store_get!(store.name);
+///     name_changes.set(*name_changes.get_untracked() + 1);
+/// }));
+/// assert_eq!(*name_changes.get(), 1);
+///
+/// // Updating a sibling field does not re-run the effect.
+/// store_set!(store.email = "john@example.org".to_string());
+/// assert_eq!(*name_changes.get(), 1);
+///
+/// store_set!(store.name = "Jane".to_string());
+/// assert_eq!(*name_changes.get(), 2);
+/// ```
+pub fn create_store<T: 'static>(initial: T) -> Store<T> {
+    Store(Rc::new(StoreInner {
+        value: RefCell::new(initial),
+        paths: RefCell::new(AHashMap::new()),
+    }))
+}
+
+impl<T: 'static> Store<T> {
+    /// Calls `f` with a reference to the state and returns the result. If inside a reactive
+    /// scope, `path` is added to the scope's dependencies.
+    ///
+    /// `f` should only access the part of the state that is described by `path`.
+    ///
+    /// # Panics
+    /// This method panics if the store is updated inside `f`.
+    pub fn with<U>(&self, path: &str, f: impl FnOnce(&T) -> U) -> U {
+        let trigger = self
+            .0
+            .paths
+            .borrow_mut()
+            .entry(normalize_path(path))
+            .or_insert_with(|| Signal::new(()))
+            .clone();
+        trigger.get();
+
+        self.with_untracked(f)
+    }
+
+    /// Calls `f` with a reference to the state and returns the result, without tracking any
+    /// dependencies.
+    pub fn with_untracked<U>(&self, f: impl FnOnce(&T) -> U) -> U {
+        f(&self.0.value.borrow())
+    }
+
+    /// Updates the state with `f` and notifies the subscribers of `path`, its parents and its
+    /// children inside a single [`batch`].
+    ///
+    /// `f` should only modify the part of the state that is described by `path`.
+    pub fn update(&self, path: &str, f: impl FnOnce(&mut T)) {
+        f(&mut self.0.value.borrow_mut());
+
+        let path = normalize_path(path);
+        let triggers = self
+            .0
+            .paths
+            .borrow()
+            .iter()
+            .filter(|(other, _)| paths_overlap(&path, other))
+            .map(|(_, trigger)| trigger.clone())
+            .collect::<Vec<_>>();
+        batch(|| {
+            for trigger in triggers {
+                trigger.trigger_subscribers();
+            }
+        });
+    }
+}
+
+impl<T: 'static> Clone for Store<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Store<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Store")
+            .field(&self.0.value.borrow())
+            .finish()
+    }
+}
+
+/// Removes empty segments from `path` so that e.g. `".user.name"` and `"user.name"` are the same
+/// path.
+fn normalize_path(path: &str) -> String {
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Returns `true` if one of the (normalized) paths is a prefix of the other one.
+fn paths_overlap(a: &str, b: &str) -> bool {
+    a.split('.')
+        .filter(|segment| !segment.is_empty())
+        .zip(b.split('.').filter(|segment| !segment.is_empty()))
+        .all(|(a, b)| a == b)
+}
+
+/// Reads a field of a [`Store`] and tracks it as a dependency. The path is derived from the field
+/// accesses. The value is cloned.
+///
+/// `store_get!(store.user.name)` is the same as
+/// `store.with("user.name", |s| s.user.name.clone())`.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// struct Position {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let store = create_store(Position { x: 1, y: 2 });
+/// assert_eq!(store_get!(store.x), 1);
+/// ```
+#[macro_export]
+macro_rules! store_get {
+    ($store:ident $(. $field:tt)*) => {
+        $store.with(
+            ::std::concat!($(".", ::std::stringify!($field)),*),
+            |s| ::std::clone::Clone::clone(&s $(. $field)*),
+        )
+    };
+}
+
+/// Sets a field of a [`Store`] and notifies the subscribers of the field. The path is derived from
+/// the field accesses.
+///
+/// `store_set!(store.user.name = value)` is the same as
+/// `store.update("user.name", |s| s.user.name = value)`.
+///
+/// # Example
+/// ```
+/// use sycamore_reactive::*;
+///
+/// struct Position {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let store = create_store(Position { x: 1, y: 2 });
+/// store_set!(store.x = 3);
+/// assert_eq!(store_get!(store.x), 3);
+/// ```
+#[macro_export]
+macro_rules! store_set {
+    ($store:ident $(. $field:tt)* = $value:expr) => {{
+        let value = $value;
+        $store.update(
+            ::std::concat!($(".", ::std::stringify!($field)),*),
+            move |s| s $(. $field)* = value,
+        )
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct User {
+        name: String,
+        email: String,
+    }
+
+    #[derive(Clone, Default)]
+    struct State {
+        user: User,
+        count: i32,
+    }
+
+    /// Creates an effect that tracks `path` and returns a signal counting how many times the
+    /// effect ran.
+    fn count_runs(store: &Store<State>, path: &'static str) -> Signal<i32> {
+        let store = store.clone();
+        let runs = Signal::new(0);
+        create_effect(cloned!((runs) => move || {
+            store.with(path, |_| {});
+            runs.set(*runs.get_untracked() + 1);
+        }));
+        runs
+    }
+
+    #[test]
+    fn nested_field_does_not_notify_sibling() {
+        let store = create_store(State::default());
+        let name = count_runs(&store, "user.name");
+        let email = count_runs(&store, "user.email");
+
+        store_set!(store.user.name = "John".to_string());
+        assert_eq!(*name.get(), 2);
+        assert_eq!(*email.get(), 1);
+        assert_eq!(store_get!(store.user.name), "John");
+    }
+
+    #[test]
+    fn notifies_parents_and_children() {
+        let store = create_store(State::default());
+        let root = count_runs(&store, "");
+        let user = count_runs(&store, "user");
+        let name = count_runs(&store, "user.name");
+        let count = count_runs(&store, "count");
+
+        store_set!(store.user.name = "John".to_string());
+        assert_eq!(*root.get(), 2);
+        assert_eq!(*user.get(), 2);
+        assert_eq!(*name.get(), 2);
+        assert_eq!(*count.get(), 1);
+
+        store_set!(store.user = User::default());
+        assert_eq!(*root.get(), 3);
+        assert_eq!(*user.get(), 3);
+        assert_eq!(*name.get(), 3);
+        assert_eq!(*count.get(), 1);
+
+        store_set!(store.count = 1);
+        assert_eq!(*root.get(), 4);
+        assert_eq!(*user.get(), 3);
+        assert_eq!(*count.get(), 2);
+    }
+
+    #[test]
+    fn memo_from_store() {
+        let store = create_store(State::default());
+        let greeting = create_memo(cloned!((store) => move || {
+            format!("Hello {}!", store_get!(store.user.name))
+        }));
+        assert_eq!(*greeting.get(), "Hello !");
+
+        store.update("user.name", |s| s.user.name.push_str("Jane"));
+        assert_eq!(*greeting.get(), "Hello Jane!");
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(normalize_path(".user.name"), "user.name");
+        assert_eq!(normalize_path(""), "");
+        assert!(paths_overlap("", "user"));
+        assert!(paths_overlap("user", "user.name"));
+        assert!(paths_overlap("user.name", "user"));
+        assert!(!paths_overlap("user.name", "user.email"));
+        assert!(!paths_overlap("user", "username"));
+    }
+}
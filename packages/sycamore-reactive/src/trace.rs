@@ -0,0 +1,140 @@
+//! Tracing of subscriber notifications for profiling.
+//!
+//! This module is only compiled when the `trace` feature is enabled. Without it, notifying
+//! subscribers is not instrumented at all and has no overhead.
+
+use std::cell::Cell;
+use std::time::Duration;
+
+thread_local! {
+    /// Notifications that take less time than this are not logged.
+    static THRESHOLD: Cell<Duration> = Cell::new(Duration::from_secs(0));
+}
+
+/// Sets the minimum time that notifying the subscribers of a signal must take in order to be
+/// logged. Defaults to `0`, i.e. every notification is logged.
+///
+/// Every call to [`Signal::trigger_subscribers`](crate::Signal::trigger_subscribers) (and
+/// therefore [`Signal::set`](crate::Signal::set)) that is not batched is timed. The number of
+/// subscribers that ran and the total time they took is logged with the [`log`] crate at the
+/// `debug` level. Notifications that are slower than the threshold can be used to find signals
+/// that fan out to many (or expensive) effects.
+///
+/// _This API requires the following crate features to be activated: `trace`_
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+///
+/// use sycamore_reactive::*;
+///
+/// // Only log notifications that take longer than 2ms.
+/// set_trace_threshold(Duration::from_millis(2));
+/// ```
+pub fn set_trace_threshold(threshold: Duration) {
+    THRESHOLD.with(|t| t.set(threshold));
+}
+
+/// Measures the time it takes to notify the subscribers of a signal.
+pub(super) struct Timer {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+    #[cfg(target_arch = "wasm32")]
+    start: f64,
+}
+
+impl Timer {
+    pub fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+            #[cfg(target_arch = "wasm32")]
+            start: js_sys::Date::now(),
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.start.elapsed();
+        #[cfg(target_arch = "wasm32")]
+        return Duration::from_secs_f64((js_sys::Date::now() - self.start).max(0.0) / 1000.0);
+    }
+
+    /// Logs the notification if it took longer than the threshold.
+    pub fn finish(self, subscribers: usize) {
+        let elapsed = self.elapsed();
+        if elapsed >= THRESHOLD.with(Cell::get) {
+            log::debug!(
+                "notified {} subscriber(s) in {:.3}ms",
+                subscribers,
+                elapsed.as_secs_f64() * 1000.0
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::sync::Once;
+    use std::time::Duration;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    use crate::*;
+
+    thread_local! {
+        /// The messages logged by the current thread. Tests run on different threads, so they do
+        /// not see each other's messages.
+        static LOGGED: RefCell<Vec<String>> = RefCell::new(Vec::new());
+    }
+
+    struct TestLogger;
+
+    impl Log for TestLogger {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= Level::Debug
+        }
+
+        fn log(&self, record: &Record) {
+            LOGGED.with(|logged| logged.borrow_mut().push(record.args().to_string()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Installs [`TestLogger`] as the global logger once.
+    fn init_logger() {
+        static LOGGER: TestLogger = TestLogger;
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            log::set_logger(&LOGGER).unwrap();
+            log::set_max_level(LevelFilter::Debug);
+        });
+    }
+
+    fn take_logged() -> Vec<String> {
+        LOGGED.with(|logged| logged.take())
+    }
+
+    #[test]
+    fn logs_notifications_above_threshold() {
+        init_logger();
+
+        let state = Signal::new(0);
+        for _ in 0..3 {
+            create_effect(cloned!((state) => move || {
+                state.get();
+            }));
+        }
+
+        state.set(1);
+        let logged = take_logged();
+        assert_eq!(logged.len(), 1);
+        assert!(logged[0].starts_with("notified 3 subscriber(s) in "));
+
+        set_trace_threshold(Duration::from_secs(60));
+        state.set(2);
+        assert!(take_logged().is_empty());
+    }
+}
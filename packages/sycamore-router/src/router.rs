@@ -1,11 +1,11 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::marker::PhantomData;
 use std::rc::Rc;
 
 use sycamore::prelude::*;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::{Element, HtmlAnchorElement, HtmlBaseElement, KeyboardEvent};
+use web_sys::{Element, History, HtmlAnchorElement, HtmlBaseElement, KeyboardEvent};
 
 use crate::Route;
 
@@ -23,8 +23,31 @@ pub trait Integration {
     fn click_handler(&self) -> Box<dyn Fn(web_sys::Event)>;
 }
 
+/// A guard registered with [`on_before_navigate`]. It is called with the path segments of the
+/// destination.
+type Guard = Rc<dyn Fn(&[&str]) -> bool>;
+
 thread_local! {
     static PATHNAME: RefCell<Option<Signal<String>>> = RefCell::new(None);
+    /// The guards that are consulted before navigating, in the order they were registered.
+    static GUARDS: RefCell<Vec<Guard>> = RefCell::new(Vec::new());
+    /// The last navigation that was cancelled by a guard. See [`resume_navigation`].
+    static BLOCKED: RefCell<Option<Blocked>> = RefCell::new(None);
+    /// The position of the current entry in the session history. The history entries store their
+    /// position as their state so that a rejected back or forward navigation can be undone with
+    /// `history.go()`.
+    static HISTORY_INDEX: Cell<i32> = Cell::new(0);
+    /// Set while a back or forward navigation is resumed with [`resume_navigation`] so that the
+    /// guards are not consulted again.
+    static RESUMING: Cell<bool> = Cell::new(false);
+}
+
+/// A navigation that was cancelled by a guard.
+enum Blocked {
+    /// A navigation to an url and whether it should replace the current history entry.
+    Url(String, bool),
+    /// A back or forward navigation to the history entry at this position.
+    Traverse(i32),
 }
 
 /// A router integration that uses the
@@ -95,15 +118,17 @@ impl Integration for HistoryIntegration {
                             let path = a_pathname
                                 .strip_prefix(&base_pathname())
                                 .unwrap_or(&a_pathname);
+                            if !guards_allow(path) {
+                                block(Blocked::Url(a_pathname.clone(), false));
+                                return;
+                            }
                             pathname.set(path.to_string());
 
                             // Update History API.
-                            let window = web_sys::window().unwrap_throw();
-                            let history = window.history().unwrap_throw();
-                            history
-                                .push_state_with_url(&JsValue::UNDEFINED, "", Some(&a_pathname))
-                                .unwrap_throw();
-                            window.scroll_to_with_x_and_y(0.0, 0.0);
+                            push_history(&a_pathname);
+                            web_sys::window()
+                                .unwrap_throw()
+                                .scroll_to_with_x_and_y(0.0, 0.0);
                         });
                     } else if Ok(&hash) != location.hash().as_ref() {
                         // Same origin, same path, different anchor.
@@ -201,10 +226,39 @@ where
         });
     });
 
+    // Number the history entries so that rejected back and forward navigations can be undone.
+    match entry_index(&history()) {
+        Some(index) => HISTORY_INDEX.with(|current| current.set(index)),
+        None => replace_history(None),
+    }
+
     // Listen to popstate event.
     integration.on_popstate(Box::new(cloned!((integration, pathname) => move || {
-        let path = integration.current_pathname();
-        let path = path.strip_prefix(&base_pathname).unwrap_or(&path);
+        let url = integration.current_pathname();
+        let path = url.strip_prefix(&base_pathname).unwrap_or(&url);
+        let current = HISTORY_INDEX.with(Cell::get);
+        let index = match entry_index(&history()) {
+            // Returned to the current entry after a rejected navigation.
+            Some(index) if index == current => return,
+            Some(index) => index,
+            None => {
+                // Entries without a position are created by the browser for anchor links to the
+                // same page, which are not guarded. They are always added after the current entry.
+                HISTORY_INDEX.with(|current| current.set(current.get() + 1));
+                replace_history(None);
+                pathname.set(path.to_string());
+                return;
+            }
+        };
+        let resuming = RESUMING.with(|resuming| resuming.replace(false));
+        if !resuming && !guards_allow(path) {
+            // The browser has already moved to the other entry. Move back to the current entry
+            // without adding a new one, so that the rest of the history is kept.
+            history().go_with_delta(current - index).unwrap_throw();
+            block(Blocked::Traverse(index));
+            return;
+        }
+        HISTORY_INDEX.with(|current| current.set(index));
         pathname.set(path.to_string());
     })));
 
@@ -290,27 +344,12 @@ where
 /// This is useful for imperatively navigating to an url when using an anchor tag (`<a>`) is not
 /// possible/suitable (e.g. when submitting a form).
 ///
+/// The navigation is cancelled if a guard registered with [`on_before_navigate`] rejects it.
+///
 /// # Panics
 /// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn navigate(url: &str) {
-    PATHNAME.with(|pathname| {
-        assert!(
-            pathname.borrow().is_some(),
-            "navigate can only be used with a BrowserRouter"
-        );
-
-        let pathname = pathname.borrow().clone().unwrap_throw();
-        let path = url.strip_prefix(&base_pathname()).unwrap_or(url);
-        pathname.set(path.to_string());
-
-        // Update History API.
-        let window = web_sys::window().unwrap_throw();
-        let history = window.history().unwrap_throw();
-        history
-            .push_state_with_url(&JsValue::UNDEFINED, "", Some(url))
-            .unwrap_throw();
-        window.scroll_to_with_x_and_y(0.0, 0.0);
-    });
+    navigate_to(url, false, true, "navigate");
 }
 
 /// Navigates to the specified `url` without adding a new history entry. Instead, this replaces the
@@ -319,26 +358,175 @@ pub fn navigate(url: &str) {
 /// This is useful for imperatively navigating to an url when using an anchor tag (`<a>`) is not
 /// possible/suitable (e.g. when submitting a form).
 ///
+/// The navigation is cancelled if a guard registered with [`on_before_navigate`] rejects it.
+///
 /// # Panics
 /// This function will `panic!()` if a [`Router`] has not yet been created.
 pub fn navigate_replace(url: &str) {
+    navigate_to(url, true, true, "navigate_replace");
+}
+
+/// Registers a guard that is consulted before the [`Router`] navigates to a new route, both when
+/// a link is clicked and when [`navigate`] or [`navigate_replace`] is called. The guard is called
+/// with the route that would be navigated to. If it returns `false`, the navigation is cancelled
+/// and the current page stays. Navigating with the back and forward buttons of the browser is
+/// guarded as well.
+///
+/// All the guards have to accept a navigation for it to happen. The guard is removed when the
+/// current reactive scope is destroyed, e.g. when the component that registered it is unmounted.
+///
+/// To ask for confirmation asynchronously, e.g. with a custom dialog, reject the navigation in the
+/// guard and call [`resume_navigation`] once the user has confirmed. Note that guards are not
+/// consulted when the page is closed or reloaded. Use the `beforeunload` event for that.
+///
+/// The browser has already moved to another history entry when a back or forward navigation is
+/// guarded, so the url briefly shows the destination until the router moves back with
+/// `history.go()`. To know where to move back to, the router stores the position of every history
+/// entry as its state. Entries that are added with `history.pushState` outside of the router are
+/// not numbered, so the router might move back to the wrong entry after such entries have been
+/// visited.
+///
+/// # Example
+/// Asking for confirmation before leaving a form with unsaved changes:
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore_router::{on_before_navigate, Route};
+///
+/// #[derive(Route)]
+/// enum AppRoutes {
+///     #[to("/")]
+///     Home,
+///     #[to("/edit")]
+///     Editor,
+///     #[not_found]
+///     NotFound,
+/// }
+///
+/// #[component(Editor<G>)]
+/// fn editor() -> View<G> {
+///     let text = Signal::new(String::new());
+///     let saved = Signal::new(String::new());
+///     let dirty = create_memo(cloned!((text, saved) => move || text.get() != saved.get()));
+///
+///     on_before_navigate(cloned!((dirty) => move |_: &AppRoutes| {
+///         !*dirty.get_untracked()
+///             || web_sys::window()
+///                 .unwrap()
+///                 .confirm_with_message("Discard unsaved changes?")
+///                 .unwrap()
+///     }));
+///
+///     let save = cloned!((text, saved) => move |_| saved.set(text.get().as_ref().clone()));
+///     view! {
+///         textarea(bind:value=text)
+///         button(on:click=save) { "Save" }
+///     }
+/// }
+/// ```
+pub fn on_before_navigate<R: Route + 'static>(guard: impl Fn(&R) -> bool + 'static) {
+    let guard: Guard = Rc::new(move |segments| guard(&R::match_route(segments)));
+    GUARDS.with(|guards| guards.borrow_mut().push(Rc::clone(&guard)));
+    on_cleanup(move || {
+        GUARDS.with(|guards| guards.borrow_mut().retain(|g| !Rc::ptr_eq(g, &guard)));
+    });
+}
+
+/// Completes the last navigation that was cancelled by a guard registered with
+/// [`on_before_navigate`], without consulting the guards again. Does nothing if no navigation was
+/// cancelled.
+///
+/// This allows guards to ask for confirmation asynchronously.
+///
+/// # Panics
+/// This function will `panic!()` if a [`Router`] has not yet been created.
+pub fn resume_navigation() {
+    match BLOCKED.with(|blocked| blocked.borrow_mut().take()) {
+        Some(Blocked::Url(url, replace)) => navigate_to(&url, replace, false, "resume_navigation"),
+        Some(Blocked::Traverse(index)) => {
+            let delta = index - HISTORY_INDEX.with(Cell::get);
+            if delta != 0 {
+                RESUMING.with(|resuming| resuming.set(true));
+                history().go_with_delta(delta).unwrap_throw();
+            }
+        }
+        None => {}
+    }
+}
+
+/// Returns `true` if all the guards accept navigating to `path`.
+fn guards_allow(path: &str) -> bool {
+    // Clone the guards so that guards can be registered or removed while they are called.
+    let guards = GUARDS.with(|guards| guards.borrow().clone());
+    if guards.is_empty() {
+        return true;
+    }
+    let segments: Vec<_> = path.split('/').filter(|s| !s.is_empty()).collect();
+    guards.iter().all(|guard| guard(&segments))
+}
+
+/// Records a navigation that was cancelled by a guard so that it can be resumed later.
+fn block(navigation: Blocked) {
+    BLOCKED.with(|blocked| *blocked.borrow_mut() = Some(navigation));
+}
+
+fn history() -> History {
+    web_sys::window().unwrap_throw().history().unwrap_throw()
+}
+
+/// Returns the position of the current history entry, or `None` if the entry was not created by
+/// the router.
+fn entry_index(history: &History) -> Option<i32> {
+    history
+        .state()
+        .ok()
+        .and_then(|state| state.as_f64())
+        .map(|index| index as i32)
+}
+
+/// Adds a history entry for `url` after the current entry.
+fn push_history(url: &str) {
+    let index = HISTORY_INDEX.with(|current| {
+        current.set(current.get() + 1);
+        current.get()
+    });
+    history()
+        .push_state_with_url(&index.into(), "", Some(url))
+        .unwrap_throw();
+}
+
+/// Replaces the current history entry. If `url` is `None`, the url is not changed.
+fn replace_history(url: Option<&str>) {
+    let index = HISTORY_INDEX.with(Cell::get);
+    history()
+        .replace_state_with_url(&index.into(), "", url)
+        .unwrap_throw();
+}
+
+fn navigate_to(url: &str, replace: bool, guarded: bool, name: &str) {
     PATHNAME.with(|pathname| {
         assert!(
             pathname.borrow().is_some(),
-            "navigate_replace can only be used with a BrowserRouter"
+            "{} can only be used with a BrowserRouter",
+            name
         );
 
         let pathname = pathname.borrow().clone().unwrap_throw();
         let path = url.strip_prefix(&base_pathname()).unwrap_or(url);
+        if guarded && !guards_allow(path) {
+            block(Blocked::Url(url.to_string(), replace));
+            return;
+        }
         pathname.set(path.to_string());
 
         // Update History API.
-        let window = web_sys::window().unwrap_throw();
-        let history = window.history().unwrap_throw();
-        history
-            .replace_state_with_url(&JsValue::UNDEFINED, "", Some(url))
-            .unwrap_throw();
-        window.scroll_to_with_x_and_y(0.0, 0.0);
+        if replace {
+            replace_history(Some(url));
+        } else {
+            push_history(url);
+        }
+        web_sys::window()
+            .unwrap_throw()
+            .scroll_to_with_x_and_y(0.0, 0.0);
     });
 }
 
@@ -405,4 +593,34 @@ mod tests {
             "Not Found"
         );
     }
+
+    #[test]
+    fn guards() {
+        #[derive(Route, PartialEq)]
+        enum Routes {
+            #[to("/")]
+            Home,
+            #[to("/edit")]
+            Editor,
+            #[not_found]
+            NotFound,
+        }
+
+        let dirty = Signal::new(true);
+        let scope = create_root(cloned!((dirty) => move || {
+            on_before_navigate(move |route: &Routes| *route == Routes::Editor || !*dirty.get());
+        }));
+
+        assert!(guards_allow("/edit"));
+        assert!(!guards_allow("/"));
+        assert!(!guards_allow("/unknown/page"));
+
+        dirty.set(false);
+        assert!(guards_allow("/"));
+
+        // The guard is removed with its scope.
+        dirty.set(true);
+        drop(scope);
+        assert!(guards_allow("/"));
+    }
 }
@@ -0,0 +1,111 @@
+//! Rendering a component that is chosen at runtime.
+
+use crate::prelude::*;
+
+/// Props for [`Dynamic`].
+pub struct DynamicProps<F, G>
+where
+    F: Fn() -> View<G> + 'static,
+    G: GenericNode,
+{
+    /// The function that renders the component. Use a function pointer such as
+    /// `fn() -> View<G>` or a boxed closure so that the signal can hold different components.
+    pub component: ReadSignal<F>,
+}
+
+/// Renders the component that is currently held by `component`.
+///
+/// Whenever `component` changes, the previously rendered view is replaced by the view returned by
+/// the new function. The reactive scope of the previous view is disposed, so its effects stop
+/// running and its cleanup callbacks are called. Signals read while rendering the component are
+/// not tracked, so only changes to `component` itself cause a re-render.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::dynamic::{Dynamic, DynamicProps};
+/// use sycamore::prelude::*;
+///
+/// #[component(ProfileTab<G>)]
+/// fn profile_tab() -> View<G> {
+///     on_cleanup(|| println!("Profile tab disposed"));
+///     view! { p { "Profile" } }
+/// }
+///
+/// #[component(SettingsTab<G>)]
+/// fn settings_tab() -> View<G> {
+///     view! { p { "Settings" } }
+/// }
+///
+/// #[component(Tabs<G>)]
+/// fn tabs() -> View<G> {
+///     let selected: Signal<fn() -> View<G>> = Signal::new(|| view! { ProfileTab() });
+///     let show_profile = cloned!((selected) => move |_| selected.set(|| view! { ProfileTab() }));
+///     let show_settings = cloned!((selected) => move |_| selected.set(|| view! { SettingsTab() }));
+///
+///     view! {
+///         button(on:click=show_profile) { "Profile" }
+///         button(on:click=show_settings) { "Settings" }
+///         Dynamic(DynamicProps { component: selected.handle() })
+///     }
+/// }
+/// ```
+#[component(Dynamic<G>)]
+pub fn dynamic<F>(props: DynamicProps<F, G>) -> View<G>
+where
+    F: Fn() -> View<G> + 'static,
+{
+    let DynamicProps { component } = props;
+
+    // Every re-execution of the memo disposes of the scope of the previous execution, which
+    // contains the effects created by the previous component.
+    View::new_dyn(move || {
+        let component = component.get();
+        untrack(|| component())
+    })
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn swaps_component_and_disposes_previous() {
+        let disposed = Rc::new(Cell::new(0));
+        let component: Signal<Box<dyn Fn() -> View<SsrNode>>> =
+            Signal::new(Box::new(cloned!((disposed) => move || {
+                on_cleanup(cloned!((disposed) => move || disposed.set(disposed.get() + 1)));
+                view! { "first" }
+            })));
+
+        let node = view! {
+            p { Dynamic(DynamicProps { component: component.handle() }) }
+        };
+        assert_eq!(sycamore::render_to_string(|| node.clone()), "<p>first</p>");
+        assert_eq!(disposed.get(), 0);
+
+        component.set(Box::new(|| view! { "second" }));
+        assert_eq!(sycamore::render_to_string(|| node.clone()), "<p>second</p>");
+        assert_eq!(disposed.get(), 1);
+    }
+
+    #[test]
+    fn signals_read_by_component_are_not_tracked() {
+        let state = Signal::new(0);
+        let renders = Rc::new(Cell::new(0));
+        let component = Signal::new(cloned!((state, renders) => move || {
+            renders.set(renders.get() + 1);
+            let value = *state.get();
+            view! { (value) }
+        }));
+
+        let node: View<SsrNode> = view! {
+            Dynamic(DynamicProps { component: component.handle() })
+        };
+        state.set(1);
+        assert_eq!(sycamore::render_to_string(|| node.clone()), "0");
+        assert_eq!(renders.get(), 1);
+    }
+}
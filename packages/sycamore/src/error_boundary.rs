@@ -0,0 +1,148 @@
+//! Error boundaries for components that return a [`Result`].
+
+use std::error::Error;
+use std::rc::Rc;
+
+use sycamore_reactive::{create_context_scope, try_use_context};
+
+use crate::prelude::*;
+
+/// Context that is provided by [`ErrorBoundary`] to its children.
+#[derive(Clone)]
+struct ErrorBoundaryContext(Signal<Option<Rc<dyn Error>>>);
+
+/// Props for [`ErrorBoundary`].
+pub struct ErrorBoundaryProps<F, E, G>
+where
+    F: FnOnce() -> View<G>,
+    E: Fn(Rc<dyn Error>) -> View<G>,
+    G: GenericNode,
+{
+    pub children: F,
+    /// The view to render instead of `children` once an error has been thrown.
+    pub fallback: E,
+}
+
+/// Catches errors returned by components rendered inside `children` and renders `fallback`
+/// instead.
+///
+/// Errors are reported by components that return a `Result<View<G>, E>` (see
+/// [`component`](sycamore_macro::component)) or manually using [`throw_error`]. Only the nearest
+/// `ErrorBoundary` catches the error.
+///
+/// # Example
+/// ```
+/// use std::num::ParseIntError;
+///
+/// use sycamore::error_boundary::{ErrorBoundary, ErrorBoundaryProps};
+/// use sycamore::prelude::*;
+///
+/// #[component(Config<G>)]
+/// fn config(raw: String) -> Result<View<G>, ParseIntError> {
+///     // Propagates the error to the nearest `ErrorBoundary`.
+///     let port: u16 = raw.parse()?;
+///
+///     Ok(view! {
+///         p { "Listening on port " (port) }
+///     })
+/// }
+///
+/// # #[component(App<G>)]
+/// # fn app() -> View<G> {
+/// view! {
+///     ErrorBoundary(ErrorBoundaryProps {
+///         children: || view! {
+///             Config("not a port".to_string())
+///         },
+///         fallback: |err| view! {
+///             p(class="error") { "Invalid config: " (err) }
+///         },
+///     })
+/// }
+/// # }
+/// ```
+#[component(ErrorBoundary<G>)]
+pub fn error_boundary<F, E>(props: ErrorBoundaryProps<F, E, G>) -> View<G>
+where
+    F: FnOnce() -> View<G>,
+    E: Fn(Rc<dyn Error>) -> View<G> + 'static,
+{
+    let ErrorBoundaryProps { children, fallback } = props;
+
+    let error = Signal::new(None);
+    let children = create_context_scope(ErrorBoundaryContext(error.clone()), children);
+
+    View::new_dyn(move || match error.get().as_ref() {
+        Some(err) => fallback(Rc::clone(err)),
+        None => children.clone(),
+    })
+}
+
+/// Reports an error to the nearest [`ErrorBoundary`], which then renders its fallback instead of
+/// its children.
+///
+/// # Panics
+/// This function panics if it is not called inside an [`ErrorBoundary`].
+pub fn throw_error(err: impl Into<Box<dyn Error>>) {
+    let err: Rc<dyn Error> = Rc::from(err.into());
+    match try_use_context::<ErrorBoundaryContext>() {
+        Some(ErrorBoundaryContext(error)) => error.set(Some(err)),
+        None => panic!("error thrown outside of an `ErrorBoundary`: {}", err),
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[component(Fallible<G>)]
+    fn fallible(fail: bool) -> Result<View<G>, String> {
+        if fail {
+            return Err("failed".to_string());
+        }
+        Ok(view! { "ok" })
+    }
+
+    fn boundary<G: Html>(fail: bool) -> View<G> {
+        view! {
+            ErrorBoundary(ErrorBoundaryProps {
+                children: move || view! { Fallible(fail) },
+                fallback: |err| view! { "error: " (err) },
+            })
+        }
+    }
+
+    #[test]
+    fn renders_children_without_error() {
+        assert_eq!(sycamore::render_to_string(|| boundary(false)), "ok");
+    }
+
+    #[test]
+    fn renders_fallback_on_error() {
+        assert_eq!(
+            sycamore::render_to_string(|| boundary(true)),
+            "error: failed"
+        );
+    }
+
+    #[test]
+    fn nearest_boundary_catches_error() {
+        let html = sycamore::render_to_string(|| {
+            view! {
+                ErrorBoundary(ErrorBoundaryProps {
+                    children: || view! {
+                        p { (boundary(true)) }
+                    },
+                    fallback: |_| view! { "outer" },
+                })
+            }
+        });
+        assert_eq!(html, "<p>error: failed</p>");
+    }
+
+    #[test]
+    #[should_panic = "error thrown outside of an `ErrorBoundary`: failed"]
+    fn should_panic_without_boundary() {
+        sycamore::render_to_string(|| view! { Fallible(true) });
+    }
+}
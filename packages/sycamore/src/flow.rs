@@ -2,12 +2,13 @@
 //!
 //! Iteration can be either _"keyed"_ or _"non keyed"_.
 //! Use the [`Keyed`] and [`Indexed`] utility components respectively.
+//! Use [`Intersperse`] to render a separator between the items.
 
 use std::hash::Hash;
 
 use crate::generic_node::GenericNode;
 use crate::prelude::*;
-use crate::reactive::{map_indexed, map_keyed};
+use crate::reactive::{map_indexed, map_keyed, ReactiveScope};
 
 /// Props for [`Keyed`].
 pub struct KeyedProps<T: 'static, F, G: GenericNode, K, Key>
@@ -107,3 +108,85 @@ where
     let mut mapped = map_indexed(iterable, move |x| template(x.clone()));
     View::new_dyn(move || View::new_fragment(mapped()))
 }
+
+/// Props for [`Intersperse`].
+pub struct IntersperseProps<T, F, G: GenericNode, K, Key, S>
+where
+    F: Fn(T) -> View<G>,
+    K: Fn(&T) -> Key,
+    Key: Clone + Hash + Eq,
+    T: Clone + PartialEq + 'static,
+    S: Fn() -> View<G>,
+{
+    pub iterable: ReadSignal<Vec<T>>,
+    pub template: F,
+    pub key: K,
+    /// Creates the view that is rendered between two items.
+    pub separator: S,
+}
+
+/// Keyed iteration with a separator between every two items, like [`Keyed`].
+///
+/// Separators are added and removed as items are added and removed. Existing separators are
+/// reused.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+///
+/// let path = Signal::new(vec!["Home", "Docs", "Getting started"]);
+///
+/// let node = view! {
+///     nav(class="breadcrumbs") {
+///         Intersperse(IntersperseProps {
+///             iterable: path.handle(),
+///             template: |segment| view! {
+///                 a(href="#") { (segment) }
+///             },
+///             key: |segment| *segment,
+///             separator: || view! { " > " },
+///         })
+///     }
+/// };
+/// # let _ : View<DomNode> = node;
+/// ```
+#[component(Intersperse<G>)]
+pub fn intersperse<T, F, K, Key, S>(props: IntersperseProps<T, F, G, K, Key, S>) -> View<G>
+where
+    F: Fn(T) -> View<G> + 'static,
+    K: Fn(&T) -> Key + 'static,
+    Key: Clone + Hash + Eq + 'static,
+    T: Clone + Eq + 'static,
+    S: Fn() -> View<G> + 'static,
+{
+    let IntersperseProps {
+        iterable,
+        template,
+        key,
+        separator,
+    } = props;
+
+    let mut mapped = map_keyed(iterable, move |x| template(x.clone()), key);
+    // Each separator is created in its own reactive scope so that it is not disposed when the
+    // list is updated.
+    let mut separators: Vec<(View<G>, ReactiveScope)> = Vec::new();
+    View::new_dyn(move || {
+        let items = mapped();
+        let needed = items.len().saturating_sub(1);
+        while separators.len() < needed {
+            let mut view = None;
+            let scope = create_root(|| view = Some(separator()));
+            separators.push((view.unwrap(), scope));
+        }
+        separators.truncate(needed);
+
+        let mut views = Vec::with_capacity(items.len() + needed);
+        for (i, item) in items.into_iter().enumerate() {
+            if i > 0 {
+                views.push(separators[i - 1].0.clone());
+            }
+            views.push(item);
+        }
+        View::new_fragment(views)
+    })
+}
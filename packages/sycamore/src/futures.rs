@@ -2,8 +2,13 @@
 
 use std::future::Future;
 
-use sycamore_reactive::current_scope;
-use wasm_bindgen_futures::spawn_local;
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_util::future::abortable;
+use futures_util::StreamExt;
+use js_sys::Promise;
+use sycamore_reactive::{current_scope, on_cleanup, pending_effect_count, ReadSignal, Signal};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 
 /// A wrapper around [`wasm_bindgen_futures::spawn_local`] that extends the current reactive scope
 /// that it is called in.
@@ -41,3 +46,131 @@ where
         }
     }
 }
+
+/// Creates a [`ReadSignal`] that is updated with every value received on `rx`.
+///
+/// A task is spawned that drains the receiver and `set`s the signal for each message. The task is
+/// cancelled when the enclosing reactive scope is dropped, after which the receiver is dropped as
+/// well and further sends on the channel will fail. The signal holds `initial` until the first
+/// message arrives.
+///
+/// If not on `wasm32` target arch, no task is spawned and the signal keeps its initial value.
+///
+/// # Example
+/// A background task reports its progress through the channel, which drives a progress bar.
+/// ```no_run
+/// use futures_channel::mpsc::unbounded;
+/// use sycamore::futures::create_signal_from_receiver;
+/// use sycamore::prelude::*;
+///
+/// # fn do_chunk_of_work(_: u32) {}
+/// #[component(Progress<G>)]
+/// fn progress() -> View<G> {
+///     let (tx, rx) = unbounded();
+///     let progress = create_signal_from_receiver(0u32, rx);
+///
+///     wasm_bindgen_futures::spawn_local(async move {
+///         for i in 1..=100 {
+///             do_chunk_of_work(i);
+///             if tx.unbounded_send(i).is_err() {
+///                 // The component was dropped. Stop working.
+///                 break;
+///             }
+///         }
+///     });
+///
+///     view! {
+///         progress(max="100", value=progress.get().to_string())
+///     }
+/// }
+/// ```
+pub fn create_signal_from_receiver<T: 'static>(
+    initial: T,
+    mut rx: UnboundedReceiver<T>,
+) -> ReadSignal<T> {
+    let signal = Signal::new(initial);
+
+    if cfg!(target_arch = "wasm32") {
+        let (task, abort_handle) = abortable({
+            let signal = signal.clone();
+            async move {
+                while let Some(value) = rx.next().await {
+                    signal.set(value);
+                }
+            }
+        });
+        spawn_local(async move {
+            let _ = task.await;
+        });
+        on_cleanup(move || abort_handle.abort());
+    }
+
+    signal.into_handle()
+}
+
+/// The maximum number of times [`flush_effects`] polls the event loop before giving up.
+const MAX_FLUSH_ITERATIONS: usize = 1000;
+
+/// Waits until all pending reactive updates have settled.
+///
+/// First, the microtasks that are already queued are run. This includes the work that hooks
+/// schedule for after the current rendering, such as observing a
+/// [`NodeRef`](crate::noderef::NodeRef) once it is mounted. Then, if effects are still queued up
+/// because reactivity is paused with [`pause_reactivity`](crate::reactive::pause_reactivity), the
+/// event loop is polled until reactivity is resumed and the queue is empty (see
+/// [`pending_effect_count`](crate::reactive::pending_effect_count)).
+///
+/// This allows tests to wait for updates deterministically instead of sleeping for an arbitrary
+/// amount of time.
+///
+/// If not on `wasm32` target arch, this function returns immediately.
+///
+/// # Panics
+/// This function panics if effects are still queued up after the event loop has been polled 1000
+/// times, e.g. because reactivity is never resumed. Otherwise, a test waiting for the effects
+/// would hang forever.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::futures::flush_effects;
+/// use sycamore::prelude::*;
+///
+/// # async fn test() {
+/// let count = Signal::new(0);
+/// let node: View<DomNode> = cloned!((count) => view! { p { (count.get()) } });
+///
+/// count.set(1);
+/// flush_effects().await;
+/// // The DOM now reflects the new value.
+/// # }
+/// ```
+pub async fn flush_effects() {
+    if !cfg!(target_arch = "wasm32") {
+        return;
+    }
+
+    JsFuture::from(Promise::resolve(&JsValue::UNDEFINED))
+        .await
+        .unwrap_throw();
+    for iteration in 0.. {
+        if pending_effect_count() == 0 {
+            break;
+        }
+        if iteration == MAX_FLUSH_ITERATIONS {
+            panic!(
+                "flush_effects: {} effect(s) still pending after polling the event loop {} times; \
+                 is reactivity paused without being resumed?",
+                pending_effect_count(),
+                MAX_FLUSH_ITERATIONS
+            );
+        }
+        // Give other tasks a chance to resume reactivity.
+        let timeout = Promise::new(&mut |resolve, _| {
+            web_sys::window()
+                .unwrap_throw()
+                .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0)
+                .unwrap_throw();
+        });
+        JsFuture::from(timeout).await.unwrap_throw();
+    }
+}
@@ -71,6 +71,13 @@ pub trait GenericNode: fmt::Debug + Clone + PartialEq + Eq + Hash + 'static {
 
     fn remove_class(&self, class: &str);
 
+    /// Sets the CSS property `name` in the inline style of a node, without affecting the other
+    /// properties. `name` can also be a custom property such as `--accent-color`. Setting a
+    /// property to an empty string removes it.
+    ///
+    /// By default, this is a no-op for backends that do not support inline styles.
+    fn set_style_property(&self, _name: &str, _value: &str) {}
+
     /// Sets a property on a node.
     fn set_property(&self, name: &str, value: &JsValue);
 
@@ -107,6 +114,9 @@ pub trait GenericNode: fmt::Debug + Clone + PartialEq + Eq + Hash + 'static {
     fn remove_self(&self);
 
     /// Add a [`EventHandler`] to the event `name`.
+    ///
+    /// Adding a handler to an event that already has handlers does not replace the existing
+    /// handlers. All the handlers are called in the order in which they were added.
     fn event(&self, name: &str, handler: Box<dyn Fn(Self::EventType)>);
 
     /// Update inner text of the node. If the node has elements, all the elements are replaced with
@@ -120,6 +130,12 @@ pub trait GenericNode: fmt::Debug + Clone + PartialEq + Eq + Hash + 'static {
 
     /// Create a deep clone of the node.
     fn clone_node(&self) -> Self;
+
+    /// Request focus for the node. If the node is not yet inserted into the document, focus is
+    /// requested once the current rendering is done.
+    ///
+    /// By default, this is a no-op for backends that do not support focus, such as [`SsrNode`].
+    fn focus(&self) {}
 }
 
 /// Trait that is implemented by all [`GenericNode`] backends that render to HTML.
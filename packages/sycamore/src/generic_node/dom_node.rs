@@ -1,15 +1,23 @@
 //! Rendering backend for the DOM.
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::rc::Rc;
 
+use ahash::AHashMap;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::{intern, JsCast};
-use web_sys::{Comment, Element, Node, Text};
+use web_sys::{
+    Comment, Document, DocumentFragment, Element, HtmlElement, Node, ShadowRoot, ShadowRootInit,
+    ShadowRootMode, Text,
+};
 
 use crate::generic_node::{GenericNode, Html};
-use crate::reactive::{create_root, on_cleanup, ReactiveScope};
+use crate::reactive::{
+    create_context_scope, create_root, on_cleanup, try_use_context, ReactiveScope,
+};
+use crate::utils::queue_microtask;
 use crate::utils::render::insert;
 use crate::view::View;
 
@@ -116,23 +124,83 @@ impl fmt::Debug for DomNode {
     }
 }
 
-fn document() -> web_sys::Document {
-    thread_local! {
-        /// Cache document since it is frequently accessed to prevent going through js-interop.
-        static DOCUMENT: web_sys::Document = web_sys::window().unwrap_throw().document().unwrap_throw();
-    };
-    DOCUMENT.with(|document| document.clone())
+/// The environment that [`DomNode`] creates its nodes in.
+///
+/// By default, nodes are created in the global `document` (see [`WebBackend`]). A different
+/// backend can be supplied with [`render_with_backend`], e.g. to record or instrument the nodes
+/// that are created in tests, or to render into the document of an iframe.
+///
+/// Only [`document`](DomBackend::document) needs to be implemented. The other methods create
+/// nodes in that document by default.
+///
+/// _This API requires the following crate features to be activated: `dom`_
+pub trait DomBackend {
+    /// Returns the document that nodes are created in.
+    fn document(&self) -> Document;
+
+    /// Creates a new element with the given tag name.
+    fn create_element(&self, tag: &str) -> Element {
+        self.document().create_element(intern(tag)).unwrap_throw()
+    }
+
+    /// Creates a new text node.
+    fn create_text_node(&self, text: &str) -> Text {
+        self.document().create_text_node(text)
+    }
+
+    /// Creates a new comment node. Empty comments are used as markers for dynamic views.
+    fn create_comment(&self, data: &str) -> Comment {
+        self.document().create_comment(data)
+    }
+
+    /// Creates a new empty [`DocumentFragment`].
+    fn create_document_fragment(&self) -> DocumentFragment {
+        self.document().create_document_fragment()
+    }
+}
+
+/// The default [`DomBackend`] which creates nodes in the global `document`.
+///
+/// _This API requires the following crate features to be activated: `dom`_
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebBackend;
+
+impl DomBackend for WebBackend {
+    fn document(&self) -> Document {
+        thread_local! {
+            /// Cache document since it is frequently accessed to prevent going through js-interop.
+            static DOCUMENT: Document = web_sys::window().unwrap_throw().document().unwrap_throw();
+        };
+        DOCUMENT.with(|document| document.clone())
+    }
+}
+
+/// The [`DomBackend`] provided by [`render_with_backend`] as a context.
+#[derive(Clone)]
+struct CurrentBackend(Rc<dyn DomBackend>);
+
+thread_local! {
+    /// The number of views rendered with [`render_with_backend`] that have not been disposed yet.
+    /// If `0`, looking up the [`CurrentBackend`] context can be skipped.
+    static CUSTOM_BACKENDS: Cell<usize> = Cell::new(0);
+    static WEB_BACKEND: Rc<dyn DomBackend> = Rc::new(WebBackend);
+}
+
+/// Returns the [`DomBackend`] that new nodes should be created with.
+fn backend() -> Rc<dyn DomBackend> {
+    if CUSTOM_BACKENDS.with(Cell::get) > 0 {
+        if let Some(CurrentBackend(backend)) = try_use_context() {
+            return backend;
+        }
+    }
+    WEB_BACKEND.with(Rc::clone)
 }
 
 impl GenericNode for DomNode {
     type EventType = web_sys::Event;
 
     fn element(tag: &str) -> Self {
-        let node = document()
-            .create_element(intern(tag))
-            .unwrap_throw()
-            .dyn_into()
-            .unwrap_throw();
+        let node = backend().create_element(tag).dyn_into().unwrap_throw();
         DomNode {
             id: Default::default(),
             node,
@@ -140,7 +208,7 @@ impl GenericNode for DomNode {
     }
 
     fn text_node(text: &str) -> Self {
-        let node = document().create_text_node(text).into();
+        let node = backend().create_text_node(text).into();
         DomNode {
             id: Default::default(),
             node,
@@ -148,7 +216,7 @@ impl GenericNode for DomNode {
     }
 
     fn marker() -> Self {
-        let node = document().create_comment("").into();
+        let node = backend().create_comment("").into();
         DomNode {
             id: Default::default(),
             node,
@@ -189,6 +257,14 @@ impl GenericNode for DomNode {
             .unwrap_throw();
     }
 
+    fn set_style_property(&self, name: &str, value: &str) {
+        self.node
+            .unchecked_ref::<HtmlElement>()
+            .style()
+            .set_property(intern(name), value)
+            .unwrap_throw();
+    }
+
     fn set_property(&self, name: &str, value: &JsValue) {
         assert!(js_sys::Reflect::set(&self.node, &name.into(), value).unwrap_throw());
     }
@@ -272,35 +348,128 @@ impl GenericNode for DomNode {
             id: Default::default(),
         }
     }
+
+    fn focus(&self) {
+        if self.node.is_connected() {
+            self.node
+                .unchecked_ref::<HtmlElement>()
+                .focus()
+                .unwrap_throw();
+        } else {
+            // The node is not mounted yet. Wait until the current rendering is done by deferring
+            // to a microtask.
+            let node = self.node.clone();
+            queue_microtask(move || {
+                if node.is_connected() {
+                    node.unchecked_ref::<HtmlElement>().focus().unwrap_throw();
+                }
+            });
+        }
+    }
 }
 
 impl Html for DomNode {
     const IS_BROWSER: bool = true;
 }
 
+thread_local! {
+    /// The scopes of the views rendered with [`render_to`] and [`hydrate_to`] that have not been
+    /// unmounted yet. They are kept alive here so that dropping the [`RenderHandle`] does not
+    /// dispose of the view.
+    static MOUNTED: RefCell<AHashMap<usize, ReactiveScope>> = RefCell::new(AHashMap::new());
+    /// The key in [`MOUNTED`] of the next view that is rendered.
+    static NEXT_MOUNT_ID: Cell<usize> = Cell::new(0);
+}
+
+/// A handle to a [`View`] rendered with [`render`], [`render_to`], [`render_to_shadow`],
+/// [`hydrate`] or [`hydrate_to`].
+///
+/// The view stays mounted when the handle is dropped. Call [`unmount`](Self::unmount) to tear it
+/// down, e.g. when the app is embedded in a page and the host removes the container, or before
+/// rendering the app again when hot-reloading.
+///
+/// _This API requires the following crate features to be activated: `dom`_
+pub struct RenderHandle {
+    id: usize,
+    view: View<DomNode>,
+    parent: Node,
+}
+
+impl RenderHandle {
+    /// Removes the nodes of the view from the parent and disposes of the reactive scope of the
+    /// view. All effects stop running and all `on_cleanup` callbacks are called, so event
+    /// listeners, timers and signal subscriptions created by the view are released.
+    pub fn unmount(self) {
+        let Self { id, view, parent } = self;
+        for node in view.flatten() {
+            let node = node.inner_element();
+            if node.parent_node().as_ref() == Some(&parent) {
+                parent.remove_child(&node).unwrap_throw();
+            }
+        }
+        // Drop the scope outside of the borrow because cleanup callbacks may render other views.
+        let scope = MOUNTED.with(|mounted| mounted.borrow_mut().remove(&id));
+        drop(scope);
+    }
+}
+
 /// Render a [`View`] into the DOM.
 /// Alias for [`render_to`] with `parent` being the `<body>` tag.
 ///
 /// _This API requires the following crate features to be activated: `dom`_
-pub fn render(template: impl FnOnce() -> View<DomNode>) {
-    let window = web_sys::window().unwrap_throw();
-    let document = window.document().unwrap_throw();
-
-    render_to(template, &document.body().unwrap_throw());
+pub fn render(template: impl FnOnce() -> View<DomNode>) -> RenderHandle {
+    render_to(template, &WebBackend.document().body().unwrap_throw())
 }
 
 /// Render a [`View`] under a `parent` node.
 /// For rendering under the `<body>` tag, use [`render`] instead.
 ///
+/// The view stays mounted until [`RenderHandle::unmount`] is called on the returned handle.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+///
+/// let container = web_sys::window()
+///     .unwrap()
+///     .document()
+///     .unwrap()
+///     .get_element_by_id("widget")
+///     .unwrap();
+/// let handle = sycamore::render_to(|| view! { p { "Embedded widget" } }, &container);
+///
+/// // Later, when the host page removes the widget:
+/// handle.unmount();
+/// ```
+///
 /// _This API requires the following crate features to be activated: `dom`_
-pub fn render_to(template: impl FnOnce() -> View<DomNode>, parent: &Node) {
-    let scope = render_get_scope(template, parent);
-
-    thread_local! {
-        static GLOBAL_SCOPES: std::cell::RefCell<Vec<ReactiveScope>> = std::cell::RefCell::new(Vec::new());
+pub fn render_to(template: impl FnOnce() -> View<DomNode>, parent: &Node) -> RenderHandle {
+    let mut view = None;
+    let scope = render_get_scope(
+        || {
+            let template = template();
+            view = Some(template.clone());
+            template
+        },
+        parent,
+    );
+
+    RenderHandle {
+        id: mount(scope),
+        view: view.unwrap_throw(),
+        parent: parent.clone(),
     }
+}
 
-    GLOBAL_SCOPES.with(|global_scopes| global_scopes.borrow_mut().push(scope));
+/// Keeps `scope` alive in [`MOUNTED`] and returns its key.
+fn mount(scope: ReactiveScope) -> usize {
+    let id = NEXT_MOUNT_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    });
+    MOUNTED.with(|mounted| mounted.borrow_mut().insert(id, scope));
+    id
 }
 
 /// Render a [`View`] under a `parent` node, in a way that can be cleaned up.
@@ -328,6 +497,150 @@ pub fn render_get_scope(template: impl FnOnce() -> View<DomNode>, parent: &Node)
     })
 }
 
+/// Render a [`View`] under a `parent` node, creating all nodes with `backend` instead of the global
+/// `document`. This includes the nodes that are created later on when the view is updated.
+///
+/// Dropping the returned [`ReactiveScope`] disposes of the view, like with [`render_get_scope`].
+///
+/// # Example
+/// ```no_run
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use sycamore::prelude::*;
+/// use sycamore::{render_with_backend, DomBackend, WebBackend};
+///
+/// /// Logs the tag name of every element that is created.
+/// struct LoggingBackend;
+///
+/// impl DomBackend for LoggingBackend {
+///     fn document(&self) -> web_sys::Document {
+///         WebBackend.document()
+///     }
+///
+///     fn create_element(&self, tag: &str) -> web_sys::Element {
+///         web_sys::console::log_1(&tag.into());
+///         WebBackend.create_element(tag)
+///     }
+/// }
+///
+/// let body = WebBackend.document().body().unwrap();
+/// let _scope = render_with_backend(|| view! { p { "Hello!" } }, &body, LoggingBackend);
+/// ```
+///
+/// _This API requires the following crate features to be activated: `dom`_
+#[must_use = "please hold onto the ReactiveScope until you want to clean things up"]
+pub fn render_with_backend(
+    template: impl FnOnce() -> View<DomNode>,
+    parent: &Node,
+    backend: impl DomBackend + 'static,
+) -> ReactiveScope {
+    CUSTOM_BACKENDS.with(|count| count.set(count.get() + 1));
+    let backend: Rc<dyn DomBackend> = Rc::new(backend);
+    create_root(|| {
+        on_cleanup(|| CUSTOM_BACKENDS.with(|count| count.set(count.get() - 1)));
+        create_context_scope(CurrentBackend(backend), || {
+            insert(
+                &DomNode {
+                    id: Default::default(),
+                    node: parent.clone(),
+                },
+                template(),
+                None,
+                None,
+                false,
+            );
+        });
+    })
+}
+
+/// Render a [`View`] into a new detached [`DocumentFragment`](web_sys::DocumentFragment).
+///
+/// This is useful for building up a subtree off-DOM and then inserting it into the document in a
+/// single operation to minimize reflows. Note that inserting a `DocumentFragment` into the DOM
+/// moves its children out of the fragment.
+///
+/// Dropping the returned [`ReactiveScope`] disposes of the view, like with [`render_get_scope`].
+///
+/// _This API requires the following crate features to be activated: `dom`_
+#[must_use = "dropping the ReactiveScope disposes of the view"]
+pub fn render_to_fragment(
+    template: impl FnOnce() -> View<DomNode>,
+) -> (web_sys::DocumentFragment, ReactiveScope) {
+    let fragment = backend().create_document_fragment();
+
+    let scope = create_root(|| {
+        insert(
+            &DomNode {
+                id: Default::default(),
+                node: fragment.clone().into(),
+            },
+            template(),
+            None,
+            None,
+            false,
+        );
+    });
+
+    (fragment, scope)
+}
+
+/// Attaches a [shadow root](https://developer.mozilla.org/en-US/docs/Web/API/ShadowRoot) to `host`
+/// and renders a [`View`] into it.
+///
+/// Styles inside the shadow root do not leak out and styles of the page do not leak in. This is
+/// useful for building widgets that are embedded into arbitrary pages, such as web components.
+///
+/// `mode` determines whether the shadow root is accessible from JavaScript outside of the shadow
+/// root:
+/// * [`ShadowRootMode::Open`](web_sys::ShadowRootMode::Open) - The shadow root can be accessed with
+///   `host.shadowRoot`.
+/// * [`ShadowRootMode::Closed`](web_sys::ShadowRootMode::Closed) - `host.shadowRoot` returns
+///   `null`. The shadow root can only be accessed through the value returned by this function.
+///
+/// Like with [`render_to`], the view stays mounted until [`RenderHandle::unmount`] is called on the
+/// returned handle.
+///
+/// # Panics
+/// This function panics if a shadow root cannot be attached to `host`, e.g. because `host` already
+/// has a shadow root or because shadow roots are not supported for the kind of element.
+///
+/// _This API requires the following crate features to be activated: `dom`_
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use web_sys::ShadowRootMode;
+///
+/// let document = web_sys::window().unwrap().document().unwrap();
+/// // A custom element that hosts the shadow root.
+/// let host = document.create_element("my-widget").unwrap();
+/// document.body().unwrap().append_child(&host).unwrap();
+///
+/// let (_shadow_root, handle) = sycamore::render_to_shadow(
+///     || view! {
+///         style { "p { color: red; }" } // Only applies inside the widget.
+///         p { "Hello from the shadow DOM!" }
+///     },
+///     &host,
+///     ShadowRootMode::Open,
+/// );
+///
+/// // Later, when the widget is removed:
+/// handle.unmount();
+/// ```
+pub fn render_to_shadow(
+    template: impl FnOnce() -> View<DomNode>,
+    host: &Element,
+    mode: ShadowRootMode,
+) -> (ShadowRoot, RenderHandle) {
+    let shadow_root = host
+        .attach_shadow(&ShadowRootInit::new(mode))
+        .expect_throw("could not attach shadow root");
+    let handle = render_to(template, &shadow_root);
+    (shadow_root, handle)
+}
+
 /// Render a [`View`] under a `parent` node by reusing existing nodes (client side
 /// hydration). Alias for [`hydrate_to`] with `parent` being the `<body>` tag.
 ///
@@ -337,11 +650,8 @@ pub fn render_get_scope(template: impl FnOnce() -> View<DomNode>, parent: &Node)
 /// created nodes. This will be fixed in a later release.
 ///
 /// _This API requires the following crate features to be activated: `dom`_
-pub fn hydrate(template: impl FnOnce() -> View<DomNode>) {
-    let window = web_sys::window().unwrap_throw();
-    let document = window.document().unwrap_throw();
-
-    hydrate_to(template, &document.body().unwrap_throw());
+pub fn hydrate(template: impl FnOnce() -> View<DomNode>) -> RenderHandle {
+    hydrate_to(template, &WebBackend.document().body().unwrap_throw())
 }
 
 /// Gets the children of an [`Element`] by collecting them into a [`Vec`]. Note that the returned
@@ -367,28 +677,33 @@ fn get_children(parent: &Element) -> Vec<Element> {
 /// **TODO**: This method currently deletes existing nodes from DOM and reinserts new
 /// created nodes. This will be fixed in a later release.
 ///
+/// The view stays mounted until [`RenderHandle::unmount`] is called on the returned handle.
+///
 /// _This API requires the following crate features to be activated: `dom`_
-pub fn hydrate_to(template: impl FnOnce() -> View<DomNode>, parent: &Node) {
+pub fn hydrate_to(template: impl FnOnce() -> View<DomNode>, parent: &Node) -> RenderHandle {
     for child in get_children(parent.unchecked_ref()) {
         child.remove();
     }
 
+    let mut view = None;
     let scope = create_root(|| {
+        let template = template();
+        view = Some(template.clone());
         insert(
             &DomNode {
                 id: Default::default(),
                 node: parent.clone(),
             },
-            template(),
+            template,
             None,
             None, // TODO
             false,
         );
     });
 
-    thread_local! {
-        static GLOBAL_SCOPES: std::cell::RefCell<Vec<ReactiveScope>> = std::cell::RefCell::new(Vec::new());
+    RenderHandle {
+        id: mount(scope),
+        view: view.unwrap_throw(),
+        parent: parent.clone(),
     }
-
-    GLOBAL_SCOPES.with(|global_scopes| global_scopes.borrow_mut().push(scope));
 }
@@ -163,7 +163,8 @@ impl GenericNode for SsrNode {
 
         if let Some(classes) = classes {
             // Make sure classes are unique.
-            let mut class_set = HashSet::<_>::from_iter(classes.split(' '));
+            let mut class_set =
+                HashSet::<_>::from_iter(classes.split(' ').filter(|class| !class.is_empty()));
 
             class_set.insert(class);
 
@@ -180,7 +181,8 @@ impl GenericNode for SsrNode {
 
         if let Some(classes) = classes {
             // Make sure classes are unique.
-            let mut class_set = HashSet::<_>::from_iter(classes.split(' '));
+            let mut class_set =
+                HashSet::<_>::from_iter(classes.split(' ').filter(|class| !class.is_empty()));
 
             class_set.remove(class);
 
@@ -188,6 +190,46 @@ impl GenericNode for SsrNode {
         }
     }
 
+    fn set_style_property(&self, name: &str, value: &str) {
+        let attributes = &mut self.unwrap_element().borrow_mut().attributes;
+
+        // Keep the other declarations of the `style` attribute in order.
+        let mut declarations = attributes
+            .get("style")
+            .map(|style| {
+                style
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|declaration| !declaration.is_empty())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let position = declarations
+            .iter()
+            .position(|declaration| declaration.split(':').next().map(str::trim) == Some(name));
+        let declaration = format!("{}: {}", name, value);
+        match (position, value.is_empty()) {
+            (Some(position), false) => declarations[position] = declaration,
+            (Some(position), true) => {
+                declarations.remove(position);
+            }
+            (None, false) => declarations.push(declaration),
+            (None, true) => {}
+        }
+
+        if declarations.is_empty() {
+            attributes.remove("style");
+        } else {
+            let style = declarations
+                .iter()
+                .map(|declaration| format!("{};", declaration))
+                .collect::<Vec<_>>()
+                .join(" ");
+            attributes.insert("style".to_string(), style);
+        }
+    }
+
     fn set_property(&self, _name: &str, _value: &JsValue) {
         // Noop.
     }
@@ -337,16 +379,18 @@ impl Html for SsrNode {
 }
 
 trait WriteToString {
-    fn write_to_string(&self, s: &mut String);
+    /// Writes the node as HTML to `s`. If `normalize` is `true`, the output is made deterministic
+    /// for comparing it in tests. See [`render_to_normalized_string`].
+    fn write_to_string(&self, s: &mut String, normalize: bool);
 }
 
 impl WriteToString for SsrNode {
-    fn write_to_string(&self, s: &mut String) {
+    fn write_to_string(&self, s: &mut String, normalize: bool) {
         match self.0.ty.as_ref() {
-            SsrNodeType::Element(x) => x.borrow().write_to_string(s),
-            SsrNodeType::Comment(x) => x.borrow().write_to_string(s),
-            SsrNodeType::Text(x) => x.borrow().write_to_string(s),
-            SsrNodeType::RawText(x) => x.borrow().write_to_string(s),
+            SsrNodeType::Element(x) => x.borrow().write_to_string(s, normalize),
+            SsrNodeType::Comment(x) => x.borrow().write_to_string(s, normalize),
+            SsrNodeType::Text(x) => x.borrow().write_to_string(s, normalize),
+            SsrNodeType::RawText(x) => x.borrow().write_to_string(s, normalize),
         }
     }
 }
@@ -359,11 +403,12 @@ pub struct Element {
 }
 
 impl WriteToString for Element {
-    fn write_to_string(&self, s: &mut String) {
+    fn write_to_string(&self, s: &mut String, normalize: bool) {
         s.reserve("<".len() + self.name.len());
         s.push('<');
         s.push_str(&self.name);
-        for (name, value) in &self.attributes {
+
+        let write_attribute = |s: &mut String, name: &str, value: &str| {
             let value_escaped = html_escape::encode_double_quoted_attribute(value);
             s.reserve(" ".len() + name.len() + "=\"".len() + value_escaped.len() + "\"".len());
             s.push(' ');
@@ -371,6 +416,28 @@ impl WriteToString for Element {
             s.push_str("=\"");
             s.push_str(&value_escaped);
             s.push('"');
+        };
+        if normalize {
+            // The order of the attributes and of the classes depends on the hash map and hash set
+            // they are stored in.
+            let mut attributes: Vec<(&str, String)> = self
+                .attributes
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.clone()))
+                .collect();
+            attributes.sort_unstable_by(|a, b| a.0.cmp(b.0));
+            for (name, value) in &mut attributes {
+                if *name == "class" {
+                    let mut classes: Vec<&str> = value.split_whitespace().collect();
+                    classes.sort_unstable();
+                    *value = classes.join(" ");
+                }
+                write_attribute(s, name, value);
+            }
+        } else {
+            for (name, value) in &self.attributes {
+                write_attribute(s, name, value);
+            }
         }
 
         // Check if self-closing tag (void-element).
@@ -379,7 +446,7 @@ impl WriteToString for Element {
         } else {
             s.push('>');
             for child in &self.children {
-                child.write_to_string(s);
+                child.write_to_string(s, normalize);
             }
             s.reserve("</".len() + self.name.len() + ">".len());
             s.push_str("</");
@@ -393,7 +460,11 @@ impl WriteToString for Element {
 pub struct Comment(String);
 
 impl WriteToString for Comment {
-    fn write_to_string(&self, s: &mut String) {
+    fn write_to_string(&self, s: &mut String, normalize: bool) {
+        if normalize && self.0.is_empty() {
+            // Empty comments are markers created by `View::empty` and dynamic views.
+            return;
+        }
         let escaped = self.0.replace("-->", "--&gt;");
         s.reserve("<!--".len() + escaped.len() + "-->".len());
         s.push_str("<!--");
@@ -406,7 +477,7 @@ impl WriteToString for Comment {
 pub struct Text(String);
 
 impl WriteToString for Text {
-    fn write_to_string(&self, s: &mut String) {
+    fn write_to_string(&self, s: &mut String, _normalize: bool) {
         s.push_str(&html_escape::encode_text_minimal(&self.0));
     }
 }
@@ -416,7 +487,7 @@ impl WriteToString for Text {
 pub struct RawText(String);
 
 impl WriteToString for RawText {
-    fn write_to_string(&self, s: &mut String) {
+    fn write_to_string(&self, s: &mut String, _normalize: bool) {
         s.push_str(&self.0);
     }
 }
@@ -429,13 +500,114 @@ pub fn render_to_string(template: impl FnOnce() -> View<SsrNode>) -> String {
     let mut ret = String::new();
     let _scope = create_root(|| {
         for node in template().flatten() {
-            node.write_to_string(&mut ret);
+            node.write_to_string(&mut ret, false);
+        }
+    });
+
+    ret
+}
+
+/// Render a [`View`] into a [`String`] like [`render_to_string`], but in a normalized form that
+/// does not change between runs. Useful for comparing the output in tests, see
+/// [`assert_view_eq!`](crate::assert_view_eq).
+///
+/// The output differs from [`render_to_string`] in the following ways:
+/// - Attributes are sorted by name and the classes in the `class` attribute are sorted.
+/// - Empty comments are left out. These are the markers that are used as placeholders by empty and
+///   dynamic views.
+///
+/// _This API requires the following crate features to be activated: `ssr`_
+pub fn render_to_normalized_string(template: impl FnOnce() -> View<SsrNode>) -> String {
+    let mut ret = String::new();
+    let _scope = create_root(|| {
+        for node in template().flatten() {
+            node.write_to_string(&mut ret, true);
         }
     });
 
     ret
 }
 
+/// Renders a [`View`] to a [`String`] inside a
+/// [Web Worker](https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API) and posts the
+/// HTML to the main thread. This moves the work of rendering a large view off the main thread.
+///
+/// Rendering with [`SsrNode`] does not access `window` or `document`, so it can run in a worker.
+/// The main thread receives the HTML as the `data` of a `message` event on its `Worker` object. It
+/// can insert the HTML into the page and then make it interactive with
+/// [`hydrate_to`](crate::hydrate_to).
+///
+/// _This API requires the following crate features to be activated: `ssr`_
+///
+/// # Panics
+/// Panics if not called inside a dedicated worker.
+///
+/// # Example
+/// The worker:
+/// ```no_run
+/// use sycamore::prelude::*;
+///
+/// #[component(LargeList<G>)]
+/// fn large_list() -> View<G> {
+///     let items = Signal::new((0..10_000).collect::<Vec<u32>>());
+///     view! {
+///         ul {
+///             Indexed(IndexedProps {
+///                 iterable: items.handle(),
+///                 template: |item| view! { li { (item) } },
+///             })
+///         }
+///     }
+/// }
+///
+/// // Called from the worker's entry point.
+/// sycamore::render_in_worker(|| view! { LargeList() });
+/// ```
+pub fn render_in_worker(template: impl FnOnce() -> View<SsrNode>) {
+    let html = render_to_string(template);
+    js_sys::global()
+        .dyn_into::<web_sys::DedicatedWorkerGlobalScope>()
+        .expect_throw("render_in_worker must be called inside a dedicated worker")
+        .post_message(&JsValue::from_str(&html))
+        .unwrap_throw();
+}
+
+/// Asserts that a view renders to the expected HTML.
+///
+/// The view is rendered with [`render_to_normalized_string`], so `expected` must list the
+/// attributes of an element and its classes in alphabetical order and must not contain the empty
+/// marker comments.
+///
+/// _This API requires the following crate features to be activated: `ssr`_
+///
+/// # Example
+/// ```
+/// use sycamore::assert_view_eq;
+/// use sycamore::prelude::*;
+///
+/// #[component(Hello<G>)]
+/// fn hello(name: String) -> View<G> {
+///     view! {
+///         p(id="greeting", class="text large") { "Hello " (name) "!" }
+///     }
+/// }
+///
+/// assert_view_eq!(
+///     view! { Hello("World".to_string()) },
+///     r#"<p class="large text" id="greeting">Hello World!</p>"#
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_view_eq {
+    ($view:expr, $expected:expr $(,)?) => {
+        assert_eq!(
+            $crate::render_to_normalized_string(|| $view),
+            $expected,
+            "rendered view does not match the expected HTML"
+        )
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -471,6 +643,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_normalized() {
+        let active = Signal::new(true);
+        assert_view_eq!(
+            view! {
+                div(id="b", class="z a m", data-x="1") {
+                    (if *active.get() { view! { "shown" } } else { View::empty() })
+                    (View::empty())
+                }
+            },
+            r#"<div class="a m z" data-x="1" id="b">shown</div>"#
+        );
+    }
+
     #[test]
     fn append_child() {
         let node = SsrNode::element("div");
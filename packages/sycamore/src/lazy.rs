@@ -0,0 +1,160 @@
+//! Deferring the rendering of expensive views.
+
+use sycamore_reactive::{create_child_scope_in, current_scope, ReactiveScope};
+
+use crate::prelude::*;
+
+/// Props for [`Lazy`].
+pub struct LazyProps<F, G>
+where
+    F: FnOnce() -> View<G>,
+    G: GenericNode,
+{
+    /// Renders `children` the first time this is `true`. Later changes are ignored.
+    pub when: ReadSignal<bool>,
+    /// A thunk that creates the deferred view. It is only called once.
+    pub children: F,
+    /// The view to render until `children` has been rendered. Use [`View::empty`] to render
+    /// nothing.
+    pub fallback: View<G>,
+}
+
+/// Defers rendering `children` until `when` becomes `true` for the first time.
+///
+/// Until then, `fallback` is rendered in its place. `children` is a thunk that is called exactly
+/// once and the resulting view is cached, so `when` becoming `false` again does not remove it. The
+/// deferred view is created in a child scope of the scope that `Lazy` is rendered in, so it can
+/// still access context that is provided by its ancestors.
+///
+/// `Lazy` does not wait for any data. It only defers creating the view. Combined with
+/// [`use_intersection`](crate::web::use_intersection), it can be used to skip the cost of rendering
+/// content below the fold until it is scrolled into view.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::lazy::{Lazy, LazyProps};
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_intersection;
+///
+/// #[component(ExpensiveChart<G>)]
+/// fn expensive_chart() -> View<G> {
+///     // Imagine thousands of SVG nodes here.
+///     view! { svg(class="chart") }
+/// }
+///
+/// #[component(Dashboard<G>)]
+/// fn dashboard() -> View<G> {
+///     let container = NodeRef::new();
+///     let visible = use_intersection(container.clone());
+///
+///     view! {
+///         div(class="summary") { "..." }
+///         div(ref=container, class="chart-container") {
+///             Lazy(LazyProps {
+///                 when: visible,
+///                 children: || view! { ExpensiveChart() },
+///                 fallback: view! { p { "Loading chart..." } },
+///             })
+///         }
+///     }
+/// }
+/// ```
+#[component(Lazy<G>)]
+pub fn lazy<F>(props: LazyProps<F, G>) -> View<G>
+where
+    F: FnOnce() -> View<G> + 'static,
+{
+    let LazyProps {
+        when,
+        children,
+        fallback,
+    } = props;
+
+    let parent = current_scope();
+    let mut children = Some(children);
+    // The scope of the rendered children is kept alive for as long as `Lazy` itself.
+    let mut rendered: Option<(View<G>, ReactiveScope)> = None;
+
+    View::new_dyn(move || {
+        // `when` is no longer tracked once the children are rendered.
+        if rendered.is_none() && *when.get() {
+            let children = children.take().unwrap();
+            let mut view = None;
+            let scope = create_child_scope_in(parent.as_ref(), || {
+                view = Some(untrack(children));
+            });
+            rendered = Some((view.unwrap(), scope));
+        }
+        match &rendered {
+            Some((view, _)) => view.clone(),
+            None => fallback.clone(),
+        }
+    })
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use sycamore_reactive::{create_context_scope, use_context};
+
+    use super::*;
+
+    #[test]
+    fn renders_children_once_when_true() {
+        let when = Signal::new(false);
+        let calls = Rc::new(Cell::new(0));
+
+        let node = cloned!((when, calls) => view! {
+            p {
+                Lazy(LazyProps {
+                    when: when.handle(),
+                    children: move || {
+                        calls.set(calls.get() + 1);
+                        view! { "content" }
+                    },
+                    fallback: view! { "fallback" },
+                })
+            }
+        });
+
+        assert_eq!(
+            sycamore::render_to_string(|| node.clone()),
+            "<p>fallback</p>"
+        );
+        assert_eq!(calls.get(), 0);
+
+        when.set(true);
+        assert_eq!(
+            sycamore::render_to_string(|| node.clone()),
+            "<p>content</p>"
+        );
+        assert_eq!(calls.get(), 1);
+
+        // The rendered children are cached and kept.
+        when.set(false);
+        when.set(true);
+        assert_eq!(
+            sycamore::render_to_string(|| node.clone()),
+            "<p>content</p>"
+        );
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn children_can_use_context() {
+        let html = sycamore::render_to_string(|| {
+            create_context_scope(42, || {
+                view! {
+                    Lazy(LazyProps {
+                        when: Signal::new(true).into_handle(),
+                        children: || view! { (use_context::<i32>()) },
+                        fallback: View::empty(),
+                    })
+                }
+            })
+        });
+        assert_eq!(html, "42");
+    }
+}
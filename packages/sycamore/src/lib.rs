@@ -6,17 +6,23 @@
 //! [Sycamore Book](https://sycamore-rs.netlify.app/docs/getting_started/installation).
 //!
 //! ## Feature Flags
+//! - `catch-panics` - Catches panics in effects so that the other subscribers of a signal are still
+//!   notified. The panic is logged instead. Requires building with `panic = "unwind"`.
+//! - `debug` - Enables debugging utilities in the reactive system, such as
+//!   [`create_effect_debug`](crate::reactive::create_effect_debug).
 //! - `dom` (_default_) - Enables rendering templates to DOM nodes. Only useful on
 //!   `wasm32-unknown-unknown` target.
 //! - `experimental-builder-agnostic` - Enables the agnostic backend builder API.
 //! - `experimental-builder-html` - Enables the HTML specific backend builder API. Also enables
 //!   `experimental-builder-agnostic`.
 //! - `futures` - Enables wrappers around `wasm-bindgen-futures` to make it easier to extend a
-//!   reactive scope into an `async` function.
+//!   reactive scope into an `async` function, and feeding channels into signals.
+//! - `markdown` - Enables the [`Markdown`](crate::markdown::Markdown) component which renders
+//!   Markdown using `pulldown-cmark`.
 //! - `ssr` - Enables rendering templates to static strings (useful for Server Side Rendering /
 //!   Pre-rendering).
 //! - `serde` - Enables serializing and deserializing `Signal`s and other wrapper types using
-//!   `serde`.
+//!   `serde`, and syncing signals to a `WebSocket`.
 //! - `wasm-bindgen-interning` (_default_) - Enables interning for `wasm-bindgen` strings. This
 //!   improves performance at a slight cost in binary size. If you want to minimize the size of the
 //!   result `.wasm` binary, you might want to disable this.
@@ -28,21 +34,32 @@
 #![deny(clippy::trait_duplication_in_bounds)]
 #![deny(clippy::type_repetition_in_bounds)]
 
-pub use sycamore_macro::{component, node, view};
+pub use sycamore_macro::{component, node, view, Slots};
 pub use sycamore_reactive as reactive;
 
 #[cfg(feature = "experimental-builder-agnostic")]
 pub mod builder;
 pub mod component;
 pub mod context;
+pub mod dynamic;
 pub mod easing;
+pub mod error_boundary;
 pub mod flow;
 pub mod generic_node;
+pub mod lazy;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod motion;
 pub mod noderef;
 pub mod portal;
+pub mod show;
+pub mod style;
+pub mod text;
 pub mod utils;
 pub mod view;
+pub mod virtualized;
+#[cfg(feature = "dom")]
+pub mod web;
 
 #[cfg(feature = "futures")]
 pub mod futures;
@@ -51,17 +68,24 @@ pub mod futures;
 extern crate self as sycamore;
 
 #[cfg(feature = "dom")]
-pub use crate::generic_node::{hydrate, hydrate_to, render, render_get_scope, render_to, DomNode};
+pub use crate::generic_node::{
+    hydrate, hydrate_to, render, render_get_scope, render_to, render_to_fragment, render_to_shadow,
+    render_with_backend, DomBackend, DomNode, WebBackend,
+};
 #[cfg(feature = "ssr")]
-pub use crate::generic_node::{render_to_string, SsrNode};
+pub use crate::generic_node::{
+    render_in_worker, render_to_normalized_string, render_to_string, SsrNode,
+};
 
 /// The sycamore prelude.
 pub mod prelude {
-    pub use sycamore_macro::{component, node, view};
+    pub use sycamore_macro::{component, node, view, Slots};
 
     #[cfg(feature = "experimental-builder-agnostic")]
     pub use crate::builder::agnostic::prelude::*;
-    pub use crate::flow::{Indexed, IndexedProps, Keyed, KeyedProps};
+    pub use crate::flow::{
+        Indexed, IndexedProps, Intersperse, IntersperseProps, Keyed, KeyedProps,
+    };
     #[cfg(feature = "dom")]
     pub use crate::generic_node::DomNode;
     pub use crate::generic_node::GenericNode;
@@ -71,7 +95,7 @@ pub mod prelude {
     pub use crate::noderef::NodeRef;
     pub use crate::reactive::{
         cloned, create_effect, create_memo, create_root, create_selector, create_selector_with,
-        on_cleanup, untrack, ReadSignal, Signal,
+        derived, on_cleanup, untrack, ReadSignal, Signal, SignalOptionExt,
     };
     pub use crate::view::{IntoView, View};
 }
@@ -79,7 +103,9 @@ pub mod prelude {
 /// Re-exports for use by `sycamore-macro`. Not intended for use by end-users.
 #[doc(hidden)]
 pub mod rt {
-    pub use js_sys::Reflect;
+    pub use js_sys::{Function, Reflect};
     pub use wasm_bindgen::{intern, JsCast, JsValue};
     pub use web_sys::Event;
+
+    pub use crate::view::{Splice, SpliceIntoView, SpliceOptionView};
 }
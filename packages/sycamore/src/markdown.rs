@@ -0,0 +1,154 @@
+//! Rendering Markdown.
+//!
+//! _This API requires the following crate features to be activated: `markdown`_
+
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag};
+
+use crate::prelude::*;
+
+/// The URL schemes that links and images are allowed to use. URLs without a scheme are relative
+/// and always allowed.
+const SAFE_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Converts Markdown to HTML that is safe to insert into the document, even if `source` is
+/// untrusted.
+///
+/// Raw HTML in `source` is escaped and rendered as text instead of being passed through. The URLs
+/// of links and images are removed if they use a scheme other than `http`, `https` or `mailto`,
+/// e.g. `javascript:`. Tables and strikethrough are supported in addition to CommonMark.
+///
+/// # Example
+/// ```
+/// use sycamore::markdown::markdown_to_html;
+///
+/// assert_eq!(markdown_to_html("# Hello"), "<h1>Hello</h1>\n");
+/// assert_eq!(
+///     markdown_to_html("<script>alert(1)</script>"),
+///     "&lt;script&gt;alert(1)&lt;/script&gt;"
+/// );
+/// ```
+pub fn markdown_to_html(source: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH;
+    let events = Parser::new_ext(source, options).map(|event| match event {
+        Event::Html(html) => Event::Text(html),
+        Event::Start(tag) => Event::Start(sanitize_tag(tag)),
+        Event::End(tag) => Event::End(sanitize_tag(tag)),
+        event => event,
+    });
+
+    let mut out = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut out, events);
+    out
+}
+
+/// Removes the URL of a link or image if it is not safe.
+fn sanitize_tag(tag: Tag<'_>) -> Tag<'_> {
+    match tag {
+        Tag::Link(kind, url, title) => Tag::Link(kind, sanitize_url(url), title),
+        Tag::Image(kind, url, title) => Tag::Image(kind, sanitize_url(url), title),
+        tag => tag,
+    }
+}
+
+fn sanitize_url(url: CowStr<'_>) -> CowStr<'_> {
+    // Browsers ignore whitespace and control characters inside the scheme, e.g. `java\tscript:`.
+    let normalized: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_ascii_control())
+        .collect();
+    // A colon after a `/`, `?` or `#` is part of a relative URL, not a scheme.
+    let scheme = normalized
+        .split(['/', '?', '#'])
+        .next()
+        .and_then(|start| start.split_once(':'))
+        .map(|(scheme, _)| scheme);
+    match scheme {
+        Some(scheme) if !SAFE_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()) => "".into(),
+        _ => url,
+    }
+}
+
+/// Renders the Markdown in `source` as HTML inside a `div`.
+///
+/// The HTML is produced by [`markdown_to_html`] and is therefore safe to render even if `source`
+/// comes from an untrusted user. The Markdown is parsed again whenever `source` changes.
+///
+/// _This API requires the following crate features to be activated: `markdown`_
+///
+/// # Example
+/// A live preview for a Markdown editor:
+/// ```no_run
+/// use sycamore::markdown::Markdown;
+/// use sycamore::prelude::*;
+///
+/// #[component(Editor<G>)]
+/// fn editor() -> View<G> {
+///     let source = Signal::new(String::from("# Title\n\nSome *emphasis*."));
+///
+///     view! {
+///         textarea(bind:value=source.clone())
+///         Markdown(source.handle())
+///     }
+/// }
+/// ```
+#[component(Markdown<G>)]
+pub fn markdown(source: ReadSignal<String>) -> View<G> {
+    let html = create_memo(move || markdown_to_html(&source.get()));
+    view! {
+        div(dangerously_set_inner_html=&html.get())
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_reactively() {
+        let source = Signal::new(String::from("*a*"));
+        let node = cloned!((source) => view! { Markdown(source.handle()) });
+
+        assert_eq!(
+            sycamore::render_to_string(|| node.clone()),
+            "<div><p><em>a</em></p>\n</div>"
+        );
+
+        source.set(String::from("**b**"));
+        assert_eq!(
+            sycamore::render_to_string(|| node.clone()),
+            "<div><p><strong>b</strong></p>\n</div>"
+        );
+    }
+
+    #[test]
+    fn escapes_raw_html() {
+        assert_eq!(
+            markdown_to_html("<img src=x onerror=alert(1)>"),
+            "&lt;img src=x onerror=alert(1)&gt;"
+        );
+        assert_eq!(
+            markdown_to_html("a <b onclick=alert(1)>b</b>"),
+            "<p>a &lt;b onclick=alert(1)&gt;b&lt;/b&gt;</p>\n"
+        );
+    }
+
+    #[test]
+    fn removes_unsafe_urls() {
+        assert_eq!(
+            markdown_to_html("[a](javascript:alert(1))"),
+            "<p><a href=\"\">a</a></p>\n"
+        );
+        assert_eq!(
+            markdown_to_html("[a](<JAVA\tSCRIPT:alert(1)>)"),
+            "<p><a href=\"\">a</a></p>\n"
+        );
+        assert_eq!(
+            markdown_to_html("![a](data:image/svg+xml,x)"),
+            "<p><img src=\"\" alt=\"a\" /></p>\n"
+        );
+        assert_eq!(
+            markdown_to_html("[a](https://example.com) [b](/docs?x=a:b)"),
+            "<p><a href=\"https://example.com\">a</a> <a href=\"/docs?x=a:b\">b</a></p>\n"
+        );
+    }
+}
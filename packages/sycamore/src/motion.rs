@@ -1,13 +1,30 @@
 //! Utilities for smooth transitions and animations.
 
 use std::cell::RefCell;
+use std::hash::Hash;
 use std::rc::Rc;
 
 use js_sys::Date;
 
-use crate::reactive::Signal;
+use crate::prelude::*;
+use crate::reactive::map_keyed;
 use crate::utils::{loop_raf, Task};
 
+thread_local! {
+    /// Shared state for [`create_raf_signal`].
+    static RAF_SIGNAL: RefCell<Option<RafSignalState>> = RefCell::new(None);
+}
+
+/// Internal state for [`create_raf_signal`]. The `requestAnimationFrame` loop is shared between
+/// all consumers.
+struct RafSignalState {
+    signal: Signal<f64>,
+    task: Task,
+    /// The number of reactive scopes that are using the signal. When this reaches `0`, the
+    /// `requestAnimationFrame` loop is stopped.
+    consumers: usize,
+}
+
 /// Describes a trait that can be linearly interpolate between two points.
 pub trait Lerp {
     /// Get a value between `self` and `other` at a `scalar`.
@@ -65,6 +82,365 @@ impl<T: Lerp + Clone, const N: usize> Lerp for [T; N] {
     }
 }
 
+/// Creates a [`ReadSignal`] that is updated with the current timestamp (in milliseconds since the
+/// UNIX epoch) on every `requestAnimationFrame`. Effects depending on the signal will therefore
+/// run once every frame.
+///
+/// Only one `requestAnimationFrame` loop is run, regardless of how many times this function is
+/// called. The loop is stopped once all the reactive scopes in which this function was called have
+/// been dropped.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::motion::create_raf_signal;
+/// use sycamore::prelude::*;
+///
+/// #[component(MovingBox<G>)]
+/// fn moving_box() -> View<G> {
+///     let time = create_raf_signal();
+///     let start = *time.get_untracked();
+///     // Move the box 100 pixels per second, wrapping around every 500 pixels.
+///     let left = create_memo(move || ((*time.get() - start) / 10.0) % 500.0);
+///
+///     view! {
+///         div(style=format!("position: absolute; left: {}px;", left.get())) { "Wheee!" }
+///     }
+/// }
+/// ```
+pub fn create_raf_signal() -> ReadSignal<f64> {
+    let signal = RAF_SIGNAL.with(|state| {
+        let mut state = state.borrow_mut();
+        let state = state.get_or_insert_with(|| {
+            let signal = Signal::new(Date::now());
+            let task = Task::new({
+                let signal = signal.clone();
+                move || {
+                    signal.set(Date::now());
+                    true
+                }
+            });
+            loop_raf(task.clone());
+
+            RafSignalState {
+                signal,
+                task,
+                consumers: 0,
+            }
+        });
+        state.consumers += 1;
+        state.signal.handle()
+    });
+
+    on_cleanup(|| {
+        RAF_SIGNAL.with(|state| {
+            let mut state = state.borrow_mut();
+            if let Some(inner) = state.as_mut() {
+                inner.consumers -= 1;
+                if inner.consumers == 0 {
+                    inner.task.abort();
+                    *state = None;
+                }
+            }
+        });
+    });
+
+    signal
+}
+
+/// Props for [`TransitionGroup`].
+pub struct TransitionGroupProps<T, F, G: GenericNode, K, Key>
+where
+    F: Fn(T) -> View<G>,
+    K: Fn(&T) -> Key,
+    Key: Clone + Hash + Eq,
+    T: Clone + PartialEq + 'static,
+{
+    pub iterable: ReadSignal<Vec<T>>,
+    pub template: F,
+    pub key: K,
+    /// The duration of the animations in milliseconds.
+    pub duration: f64,
+}
+
+/// Keyed iteration like [`Keyed`](crate::flow::Keyed), but items are animated when they are
+/// inserted, removed or moved.
+///
+/// Moved items are animated using the
+/// [FLIP technique](https://aerotwist.com/blog/flip-your-animations/): the position of every item
+/// is measured before and after the list is updated and moved items are smoothly transitioned
+/// from their old position to their new position. Inserted items fade in. Removed items fade out
+/// at their old position. Because removed items are positioned absolutely while fading out, the
+/// parent element should be positioned (e.g. `position: relative`).
+///
+/// The first element node of each item is animated. Animations only run in the browser.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::motion::{TransitionGroup, TransitionGroupProps};
+/// use sycamore::prelude::*;
+///
+/// #[component(SortableList<G>)]
+/// fn sortable_list() -> View<G> {
+///     let items = Signal::new(vec!["Apple", "Banana", "Cherry", "Durian"]);
+///
+///     let reverse = cloned!((items) => move |_| {
+///         items.set(items.get().iter().rev().copied().collect());
+///     });
+///
+///     view! {
+///         button(on:click=reverse) { "Reverse" }
+///         ul(style="position: relative;") {
+///             TransitionGroup(TransitionGroupProps {
+///                 iterable: items.handle(),
+///                 template: |item| view! { li { (item) } },
+///                 key: |item| *item,
+///                 duration: 300.0,
+///             })
+///         }
+///     }
+/// }
+/// ```
+#[component(TransitionGroup<G>)]
+pub fn transition_group<T, F, K, Key>(props: TransitionGroupProps<T, F, G, K, Key>) -> View<G>
+where
+    F: Fn(T) -> View<G> + 'static,
+    K: Fn(&T) -> Key + 'static,
+    Key: Clone + Hash + Eq + 'static,
+    T: Clone + Eq + 'static,
+{
+    let TransitionGroupProps {
+        iterable,
+        template,
+        key,
+        duration,
+    } = props;
+
+    let key = Rc::new(key);
+    let mut mapped = map_keyed(
+        iterable.clone(),
+        move |x| template(x.clone()),
+        cloned!((key) => move |x| key(x)),
+    );
+
+    #[cfg(feature = "dom")]
+    let mut flip = flip::FlipState::new(duration);
+    #[cfg(not(feature = "dom"))]
+    let _ = duration;
+
+    View::new_dyn(move || {
+        let views = mapped();
+
+        #[cfg(feature = "dom")]
+        {
+            if G::IS_BROWSER {
+                let items = iterable.get_untracked();
+                let entries = items
+                    .iter()
+                    .zip(&views)
+                    .filter_map(|(item, view)| Some((key(item), flip::first_element(view)?)))
+                    .collect();
+                flip.update(entries);
+            }
+        }
+
+        View::new_fragment(views)
+    })
+}
+
+/// Implementation of the animations for [`TransitionGroup`].
+#[cfg(feature = "dom")]
+mod flip {
+    use std::any::Any;
+    use std::hash::Hash;
+
+    use ahash::AHashMap;
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::{DomRect, Element, HtmlElement};
+
+    use crate::generic_node::{DomNode, GenericNode};
+    use crate::utils::queue_microtask;
+    use crate::view::View;
+
+    /// Returns the first element node of the view, if any.
+    pub fn first_element<G: GenericNode>(view: &View<G>) -> Option<Element> {
+        view.clone().flatten().iter().find_map(|node| {
+            <dyn Any>::downcast_ref::<DomNode>(node)?
+                .inner_element()
+                .dyn_into::<Element>()
+                .ok()
+        })
+    }
+
+    pub struct FlipState<Key> {
+        /// The elements that were rendered by the previous update, in order.
+        previous: Vec<(Key, Element)>,
+        /// Whether the list has been rendered before. Items are not animated on the initial
+        /// render.
+        initialized: bool,
+        duration: f64,
+    }
+
+    impl<Key: Clone + Hash + Eq + 'static> FlipState<Key> {
+        pub fn new(duration: f64) -> Self {
+            Self {
+                previous: Vec::new(),
+                initialized: false,
+                duration,
+            }
+        }
+
+        /// Must be called before the DOM is updated with the new `entries`. The animations are
+        /// started once the DOM has been updated.
+        pub fn update(&mut self, entries: Vec<(Key, Element)>) {
+            let duration = self.duration;
+            let initialized = self.initialized;
+            self.initialized = true;
+
+            // First: measure the elements before the DOM is updated.
+            let first: AHashMap<Key, DomRect> = self
+                .previous
+                .iter()
+                .map(|(key, element)| (key.clone(), element.get_bounding_client_rect()))
+                .collect();
+
+            let current_keys = entries.iter().map(|(key, _)| key).collect::<Vec<_>>();
+            let leaving = self
+                .previous
+                .iter()
+                .filter(|(key, _)| !current_keys.contains(&key))
+                .filter_map(|(_, element)| Ghost::new(element))
+                .collect::<Vec<_>>();
+
+            self.previous = entries.clone();
+
+            queue_microtask(move || {
+                for ghost in leaving {
+                    ghost.fade_out(duration);
+                }
+                for (key, element) in entries {
+                    match first.get(&key) {
+                        Some(first) => animate_move(&element, first, duration),
+                        None if initialized => animate_enter(&element, duration),
+                        None => {}
+                    }
+                }
+            });
+        }
+    }
+
+    /// Last, Invert, Play: move the element back to its old position without a transition and then
+    /// transition it to its new position.
+    fn animate_move(element: &Element, first: &DomRect, duration: f64) {
+        let last = element.get_bounding_client_rect();
+        let (dx, dy) = (first.left() - last.left(), first.top() - last.top());
+        if dx == 0.0 && dy == 0.0 {
+            return;
+        }
+
+        let style = element.unchecked_ref::<HtmlElement>().style();
+        style.set_property("transition", "none").unwrap_throw();
+        style
+            .set_property("transform", &format!("translate({}px, {}px)", dx, dy))
+            .unwrap_throw();
+        // Force a reflow so that the transform is applied before the transition is enabled.
+        let _ = element.get_bounding_client_rect();
+        style
+            .set_property("transition", &format!("transform {}ms", duration))
+            .unwrap_throw();
+        style.remove_property("transform").unwrap_throw();
+
+        clear_transition_after(element.clone(), duration);
+    }
+
+    fn animate_enter(element: &Element, duration: f64) {
+        let style = element.unchecked_ref::<HtmlElement>().style();
+        style.set_property("transition", "none").unwrap_throw();
+        style.set_property("opacity", "0").unwrap_throw();
+        let _ = element.get_bounding_client_rect();
+        style
+            .set_property("transition", &format!("opacity {}ms", duration))
+            .unwrap_throw();
+        style.remove_property("opacity").unwrap_throw();
+
+        clear_transition_after(element.clone(), duration);
+    }
+
+    fn clear_transition_after(element: Element, duration: f64) {
+        set_timeout(
+            move || {
+                let style = element.unchecked_ref::<HtmlElement>().style();
+                style.remove_property("transition").unwrap_throw();
+            },
+            duration,
+        );
+    }
+
+    fn set_timeout(f: impl FnOnce() + 'static, duration: f64) {
+        web_sys::window()
+            .unwrap_throw()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                Closure::once_into_js(f).unchecked_ref(),
+                duration as i32,
+            )
+            .unwrap_throw();
+    }
+
+    /// A copy of an element that is being removed, which is faded out at the old position of the
+    /// element.
+    struct Ghost {
+        parent: Element,
+        element: HtmlElement,
+    }
+
+    impl Ghost {
+        /// Creates a copy of `element`. Must be called before the element is removed from the DOM.
+        fn new(element: &Element) -> Option<Self> {
+            let parent = element.parent_element()?;
+            let original = element.unchecked_ref::<HtmlElement>();
+            let ghost = original
+                .clone_node_with_deep(true)
+                .unwrap_throw()
+                .unchecked_into::<HtmlElement>();
+
+            let style = ghost.style();
+            for (property, value) in [
+                ("position", "absolute".to_string()),
+                ("top", format!("{}px", original.offset_top())),
+                ("left", format!("{}px", original.offset_left())),
+                ("width", format!("{}px", original.offset_width())),
+                ("height", format!("{}px", original.offset_height())),
+                ("margin", "0".to_string()),
+                ("pointer-events", "none".to_string()),
+            ] {
+                style.set_property(property, &value).unwrap_throw();
+            }
+
+            Some(Self {
+                parent,
+                element: ghost,
+            })
+        }
+
+        fn fade_out(self, duration: f64) {
+            self.parent.append_child(&self.element).unwrap_throw();
+            let style = self.element.style();
+            let _ = self.element.get_bounding_client_rect();
+            style
+                .set_property("transition", &format!("opacity {}ms", duration))
+                .unwrap_throw();
+            style.set_property("opacity", "0").unwrap_throw();
+
+            set_timeout(
+                move || {
+                    let _ = self.parent.remove_child(&self.element);
+                },
+                duration,
+            );
+        }
+    }
+}
+
 /// A state that is interpolated when it is set.
 pub struct Tweened<T: Lerp + Clone + 'static>(Rc<RefCell<TweenedInner<T>>>);
 
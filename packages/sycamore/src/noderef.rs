@@ -2,11 +2,14 @@
 
 use std::cell::RefCell;
 use std::fmt;
+use std::hash::Hash;
 use std::rc::Rc;
 
+use ahash::AHashMap;
 use wasm_bindgen::prelude::*;
 
 use crate::generic_node::GenericNode;
+use crate::reactive::on_cleanup;
 use std::any::Any;
 
 /// A reference to a [`GenericNode`].
@@ -76,8 +79,129 @@ impl<G: GenericNode> fmt::Debug for NodeRef<G> {
     }
 }
 
+/// A collection of [`NodeRef`]s, keyed by `K`.
+///
+/// This is useful for getting references to the nodes that are rendered for the items of a list,
+/// e.g. to measure them. Use [`RefMap::entry`] as the `ref` of the node that should be referenced
+/// for an item.
+///
+/// The entry for a key is removed when the reactive scope in which [`RefMap::entry`] was called
+/// is dropped. Inside `Keyed` and `Indexed`, this is the case when the row is removed from the
+/// list, so the map only contains the rows that are currently rendered.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::noderef::RefMap;
+/// use sycamore::prelude::*;
+///
+/// #[component(Rows<G>)]
+/// fn rows() -> View<G> {
+///     let items = Signal::new(vec![1, 2, 3]);
+///     let refs = RefMap::new();
+///
+///     let measure = cloned!((refs) => move |_| {
+///         for (key, node) in refs.entries() {
+///             let height = node
+///                 .get::<DomNode>()
+///                 .unchecked_into::<web_sys::Element>()
+///                 .get_bounding_client_rect()
+///                 .height();
+///             web_sys::console::log_1(&format!("Row {} is {}px high", key, height).into());
+///         }
+///     });
+///
+///     view! {
+///         button(on:click=measure) { "Measure" }
+///         ul {
+///             Keyed(KeyedProps {
+///                 iterable: items.handle(),
+///                 template: move |item| view! { li(ref=refs.entry(item)) { (item) } },
+///                 key: |item| *item,
+///             })
+///         }
+///     }
+/// }
+/// # use wasm_bindgen::JsCast;
+/// ```
+pub struct RefMap<K, G: GenericNode>(Rc<RefCell<AHashMap<K, NodeRef<G>>>>);
+
+impl<K: Eq + Hash + Clone + 'static, G: GenericNode + Any> RefMap<K, G> {
+    /// Creates an empty [`RefMap`].
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(AHashMap::new())))
+    }
+
+    /// Inserts a new empty [`NodeRef`] for `key` and returns it. An existing entry for `key` is
+    /// replaced, e.g. when a row of an `Indexed` list is re-created for an item that was rendered
+    /// in another row before.
+    ///
+    /// The entry is removed when the current reactive scope is dropped, unless it has been replaced
+    /// in the meantime.
+    pub fn entry(&self, key: K) -> NodeRef<G> {
+        let node_ref = NodeRef::new();
+        self.0.borrow_mut().insert(key.clone(), node_ref.clone());
+
+        let map = Rc::downgrade(&self.0);
+        let entry = node_ref.clone();
+        on_cleanup(move || {
+            if let Some(map) = map.upgrade() {
+                let mut map = map.borrow_mut();
+                // The key might have been reused by a new row in the meantime.
+                if matches!(map.get(&key), Some(current) if Rc::ptr_eq(&current.0, &entry.0)) {
+                    map.remove(&key);
+                }
+            }
+        });
+
+        node_ref
+    }
+
+    /// Returns the [`NodeRef`] for `key` or `None` if there is no entry for `key`.
+    pub fn get(&self, key: &K) -> Option<NodeRef<G>> {
+        self.0.borrow().get(key).cloned()
+    }
+
+    /// Returns all the entries in the map. The order of the entries is unspecified.
+    pub fn entries(&self) -> Vec<(K, NodeRef<G>)> {
+        self.0
+            .borrow()
+            .iter()
+            .map(|(key, node_ref)| (key.clone(), node_ref.clone()))
+            .collect()
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+}
+
+impl<K: Eq + Hash + Clone + 'static, G: GenericNode + Any> Default for RefMap<K, G> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, G: GenericNode> Clone for RefMap<K, G> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+
+impl<K: fmt::Debug, G: GenericNode> fmt::Debug for RefMap<K, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RefMap").field(&self.0.borrow()).finish()
+    }
+}
+
 #[cfg(all(test, feature = "ssr"))]
 mod tests {
+    use crate::prelude::*;
     use crate::{DomNode, SsrNode};
 
     use super::*;
@@ -106,4 +230,68 @@ mod tests {
         assert_eq!(noderef.try_get::<SsrNode>(), Some(node));
         assert!(noderef.try_get::<DomNode>().is_none());
     }
+
+    #[test]
+    fn ref_map_removes_rows() {
+        let items = Signal::new(vec![1, 2, 3]);
+        let refs = RefMap::<i32, SsrNode>::new();
+
+        let scope = create_root(cloned!((items, refs) => move || {
+            let _: View<SsrNode> = view! {
+                ul {
+                    Keyed(KeyedProps {
+                        iterable: items.handle(),
+                        template: move |item| view! { li(ref=refs.entry(item)) { (item) } },
+                        key: |item| *item,
+                    })
+                }
+            };
+        }));
+        assert_eq!(refs.len(), 3);
+        let row = refs.get(&2).unwrap().get::<SsrNode>();
+        assert_eq!(
+            sycamore::render_to_string(|| View::new_node(row)),
+            "<li>2</li>"
+        );
+
+        items.set(vec![1, 3]);
+        assert_eq!(refs.len(), 2);
+        assert!(refs.get(&2).is_none());
+
+        items.set(vec![1, 2, 3]);
+        assert_eq!(refs.len(), 3);
+        assert!(refs.get(&2).unwrap().try_get_raw().is_some());
+
+        drop(scope);
+        assert!(refs.is_empty());
+    }
+
+    #[test]
+    fn ref_map_indexed_reorder() {
+        let items = Signal::new(vec![1, 2]);
+        let refs = RefMap::<i32, SsrNode>::new();
+
+        let _scope = create_root(cloned!((items, refs) => move || {
+            let _: View<SsrNode> = view! {
+                ul {
+                    Indexed(IndexedProps {
+                        iterable: items.handle(),
+                        template: move |item| view! { li(ref=refs.entry(item)) { (item) } },
+                    })
+                }
+            };
+        }));
+        assert_eq!(refs.len(), 2);
+
+        // The new rows are created before the old rows are dropped.
+        items.set(vec![2, 1]);
+        assert_eq!(refs.len(), 2);
+        for key in [1, 2] {
+            let row = refs.get(&key).unwrap().get::<SsrNode>();
+            assert_eq!(
+                sycamore::render_to_string(|| View::new_node(row)),
+                format!("<li>{}</li>", key)
+            );
+        }
+    }
 }
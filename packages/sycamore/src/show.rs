@@ -0,0 +1,215 @@
+//! Conditional rendering components.
+
+use crate::prelude::*;
+
+/// Props for [`Show`].
+pub struct ShowProps<F, G>
+where
+    F: Fn() -> View<G> + 'static,
+    G: GenericNode,
+{
+    pub when: ReadSignal<bool>,
+    /// Renders the content if `when` is `true`.
+    pub children: F,
+    /// Renders the content if `when` is `false`. Nothing is rendered if this is `None`.
+    pub fallback: Option<Box<dyn Fn() -> View<G>>>,
+}
+
+/// Renders `children` only while `when` is `true`, and `fallback` otherwise.
+///
+/// Unlike an inline `if` expression, the views are only created when they are shown. When `when`
+/// changes, the previous view is replaced and its reactive scope is disposed, so effects created
+/// inside `children` stop running while it is hidden. Setting `when` to the value it already has
+/// does not re-render anything. Signals read inside `children` and `fallback` while creating the
+/// view are not tracked.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::show::{Show, ShowProps};
+///
+/// #[component(Settings<G>)]
+/// fn settings() -> View<G> {
+///     let expanded = Signal::new(false);
+///     let toggle = cloned!((expanded) => move |_| expanded.set(!*expanded.get()));
+///
+///     view! {
+///         button(on:click=toggle) { "Advanced settings" }
+///         Show(ShowProps {
+///             when: expanded.handle(),
+///             children: || view! { div(class="panel") { "Advanced settings go here." } },
+///             fallback: Some(Box::new(|| view! { p { "Click to expand." } })),
+///         })
+///     }
+/// }
+/// ```
+#[component(Show<G>)]
+pub fn show<F>(props: ShowProps<F, G>) -> View<G>
+where
+    F: Fn() -> View<G> + 'static,
+{
+    let ShowProps {
+        when,
+        children,
+        fallback,
+    } = props;
+    let when = create_selector(move || *when.get());
+
+    // Every re-execution of the memo disposes of the scope of the previous view.
+    View::new_dyn(move || {
+        if *when.get() {
+            untrack(&children)
+        } else if let Some(fallback) = &fallback {
+            untrack(fallback)
+        } else {
+            View::empty()
+        }
+    })
+}
+
+/// Props for [`ShowResult`].
+pub struct ShowResultProps<T, E, F, H, G>
+where
+    T: 'static,
+    E: 'static,
+    F: Fn(T) -> View<G> + 'static,
+    H: Fn(E) -> View<G> + 'static,
+    G: GenericNode,
+{
+    pub result: ReadSignal<Result<T, E>>,
+    /// Renders the value if `result` is `Ok`.
+    pub ok: F,
+    /// Renders the error if `result` is `Err`.
+    pub err: H,
+}
+
+/// Renders `ok` with the value if `result` is `Ok` and `err` with the error if it is `Err`.
+///
+/// Whenever `result` changes, the previous view is replaced and its reactive scope is disposed.
+/// Signals read inside `ok` and `err` while creating the view are not tracked, so only changes to
+/// `result` itself cause a re-render.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::show::{ShowResult, ShowResultProps};
+///
+/// #[component(UserName<G>)]
+/// fn user_name(user: ReadSignal<Result<String, String>>) -> View<G> {
+///     view! {
+///         ShowResult(ShowResultProps {
+///             result: user,
+///             ok: |name| view! { p(class="name") { (name) } },
+///             err: |error| view! { p(class="error") { "Could not load user: " (error) } },
+///         })
+///     }
+/// }
+/// ```
+#[component(ShowResult<G>)]
+pub fn show_result<T, E, F, H>(props: ShowResultProps<T, E, F, H, G>) -> View<G>
+where
+    T: Clone + 'static,
+    E: Clone + 'static,
+    F: Fn(T) -> View<G> + 'static,
+    H: Fn(E) -> View<G> + 'static,
+{
+    let ShowResultProps { result, ok, err } = props;
+
+    // Every re-execution of the memo disposes of the scope of the previous view.
+    View::new_dyn(move || match result.get().as_ref() {
+        Ok(value) => untrack(|| ok(value.clone())),
+        Err(error) => untrack(|| err(error.clone())),
+    })
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn show_disposes_hidden_children() {
+        let when = Signal::new(true);
+        let count = Signal::new(0);
+        let runs = Rc::new(Cell::new(0));
+
+        let node = cloned!((when, count, runs) => view! {
+            div {
+                Show(ShowProps {
+                    when: when.handle(),
+                    children: move || {
+                        create_effect(cloned!((count, runs) => move || {
+                            count.get();
+                            runs.set(runs.get() + 1);
+                        }));
+                        view! { p { "shown" } }
+                    },
+                    fallback: Some(Box::new(|| view! { span { "hidden" } })),
+                })
+            }
+        });
+        assert_eq!(
+            sycamore::render_to_string(cloned!((node) => move || node)),
+            "<div><p>shown</p></div>"
+        );
+        count.set(1);
+        assert_eq!(runs.get(), 2);
+
+        when.set(false);
+        assert_eq!(
+            sycamore::render_to_string(cloned!((node) => move || node)),
+            "<div><span>hidden</span></div>"
+        );
+        // The effect of the hidden children has been disposed.
+        count.set(2);
+        assert_eq!(runs.get(), 2);
+
+        // Setting the same value does not re-create the children.
+        when.set(true);
+        when.set(true);
+        assert_eq!(runs.get(), 3);
+        assert_eq!(
+            sycamore::render_to_string(|| node),
+            "<div><p>shown</p></div>"
+        );
+    }
+
+    #[test]
+    fn show_result_switches_branches() {
+        let disposed = Rc::new(Cell::new(0));
+        let result: Signal<Result<i32, String>> = Signal::new(Ok(1));
+
+        let node = cloned!((result, disposed) => view! {
+            div {
+                ShowResult(ShowResultProps {
+                    result: result.handle(),
+                    ok: cloned!((disposed) => move |value| {
+                        on_cleanup(cloned!((disposed) => move || disposed.set(disposed.get() + 1)));
+                        view! { p { (value) } }
+                    }),
+                    err: |error| view! { span { (error) } },
+                })
+            }
+        });
+        assert_eq!(
+            sycamore::render_to_string(cloned!((node) => move || node)),
+            "<div><p>1</p></div>"
+        );
+
+        result.set(Ok(2));
+        assert_eq!(
+            sycamore::render_to_string(cloned!((node) => move || node)),
+            "<div><p>2</p></div>"
+        );
+        assert_eq!(disposed.get(), 1);
+
+        result.set(Err("not found".to_string()));
+        assert_eq!(
+            sycamore::render_to_string(|| node),
+            "<div><span>not found</span></div>"
+        );
+        assert_eq!(disposed.get(), 2);
+    }
+}
@@ -0,0 +1,92 @@
+//! Scoped CSS for components.
+
+use std::cell::RefCell;
+
+use ahash::AHashSet;
+
+thread_local! {
+    /// Class names of the stylesheets that have already been injected into the document.
+    static INJECTED: RefCell<AHashSet<String>> = RefCell::new(AHashSet::new());
+}
+
+/// Scopes a stylesheet to a unique class and returns the class name. Every `&` in `css` is replaced
+/// by a selector for the class, so rules should always start with `&`.
+///
+/// When running in the browser, the stylesheet is injected into the `<head>` of the document in a
+/// `<style>` element. The stylesheet is only injected once, no matter how many times this function
+/// is called with the same `css`.
+///
+/// The class name is derived from the contents of `css`. It is therefore the same when rendering
+/// on the server and when hydrating on the client.
+///
+/// # Example
+/// ```
+/// use sycamore::prelude::*;
+/// use sycamore::style::scoped_css;
+///
+/// #[component(Button<G>)]
+/// fn button() -> View<G> {
+///     let class = scoped_css(r#"
+///         & { padding: 4px 8px; border-radius: 4px; }
+///         &:hover { background: lightgray; }
+///     "#);
+///
+///     view! {
+///         button(class=class) { "Click me" }
+///     }
+/// }
+/// ```
+pub fn scoped_css(css: &str) -> String {
+    let class = format!("sycamore-{:08x}", fnv1a(css));
+
+    if cfg!(target_arch = "wasm32") {
+        let inserted = INJECTED.with(|injected| injected.borrow_mut().insert(class.clone()));
+        if inserted {
+            inject_stylesheet(&class, &css.replace('&', &format!(".{}", class)));
+        }
+    }
+
+    class
+}
+
+/// Appends a `<style>` element with `css` to the `<head>` of the document. Does nothing if there is
+/// no document, e.g. when rendering inside a Web Worker.
+fn inject_stylesheet(class: &str, css: &str) {
+    use wasm_bindgen::prelude::*;
+
+    let document = match web_sys::window().and_then(|window| window.document()) {
+        Some(document) => document,
+        None => return,
+    };
+    let style = document.create_element("style").unwrap_throw();
+    style
+        .set_attribute("data-sycamore-css", class)
+        .unwrap_throw();
+    style.set_text_content(Some(css));
+    document
+        .head()
+        .expect_throw("document should have a <head>")
+        .append_child(&style)
+        .unwrap_throw();
+}
+
+/// 32 bit FNV-1a hash. Used instead of the standard library hasher because the class names must be
+/// stable between the server and the client.
+fn fnv1a(s: &str) -> u32 {
+    s.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_is_deterministic() {
+        let class = scoped_css("& { color: red; }");
+        assert_eq!(class, scoped_css("& { color: red; }"));
+        assert_ne!(class, scoped_css("& { color: blue; }"));
+        assert!(class.starts_with("sycamore-"));
+    }
+}
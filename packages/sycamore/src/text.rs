@@ -0,0 +1,62 @@
+//! Reactive text nodes.
+
+use crate::prelude::*;
+
+/// Renders the value of a signal as a single text node.
+///
+/// The value is never interpreted as HTML, even if it contains characters such as `<` or `&`. This
+/// makes it safe to use for user generated content. Unlike
+/// [`dangerously_set_inner_html`](crate::generic_node::GenericNode::dangerously_set_inner_html),
+/// no elements can ever be created from `value`.
+///
+/// When `value` changes, the contents of the existing text node are updated instead of creating a
+/// new node.
+///
+/// Interpolating a value in [`view!`] with `(expr)` also always creates text nodes. This component
+/// makes the intent explicit.
+///
+/// # Example
+/// ```
+/// use sycamore::prelude::*;
+/// use sycamore::text::Text;
+///
+/// #[component(Comment<G>)]
+/// fn comment(body: ReadSignal<String>) -> View<G> {
+///     view! {
+///         p(class="comment") { Text(body) }
+///     }
+/// }
+/// ```
+#[component(Text<G>)]
+pub fn text(value: ReadSignal<String>) -> View<G> {
+    let node = G::text_node(&value.get_untracked());
+    create_effect({
+        let node = node.clone();
+        move || node.update_inner_text(&value.get())
+    });
+    View::new_node(node)
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_markup_literally() {
+        let value = Signal::new("<script>alert(1)</script>".to_string());
+        let node = cloned!((value) => view! {
+            p { Text(value.handle()) }
+        });
+
+        assert_eq!(
+            sycamore::render_to_string(|| node.clone()),
+            "<p>&lt;script>alert(1)&lt;/script></p>"
+        );
+
+        value.set("Tom & Jerry".to_string());
+        assert_eq!(
+            sycamore::render_to_string(|| node.clone()),
+            "<p>Tom &amp; Jerry</p>"
+        );
+    }
+}
@@ -105,3 +105,15 @@ pub fn loop_raf(task: Task) {
         tasks.borrow_mut().insert(task);
     });
 }
+
+/// Runs a callback in a microtask, i.e. once the currently executing code has finished but before
+/// control is returned to the browser's event loop.
+#[cfg(feature = "dom")]
+pub fn queue_microtask(f: impl FnOnce() + 'static) {
+    use js_sys::Promise;
+    use wasm_bindgen::prelude::*;
+
+    let callback = Closure::once(move |_: JsValue| f());
+    let _ = Promise::resolve(&JsValue::UNDEFINED).then(&callback);
+    callback.forget();
+}
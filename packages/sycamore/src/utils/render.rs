@@ -1,12 +1,14 @@
 //! Utilities for rendering nodes.
 
+use std::collections::{BTreeSet, HashSet};
+use std::hash::BuildHasher;
 use std::rc::Rc;
 
 use ahash::AHashMap;
 use wasm_bindgen::UnwrapThrowExt;
 
 use crate::generic_node::GenericNode;
-use crate::reactive::create_effect;
+use crate::reactive::{create_effect, ReadSignal};
 use crate::view::{View, ViewType};
 
 /// Insert a [`GenericNode`] under `parent` at the specified `marker`. If `initial` is `Some(_)`,
@@ -363,3 +365,91 @@ pub fn reconcile_fragments<G: GenericNode>(parent: &G, a: &mut [G], b: &[G]) {
         }
     }
 }
+
+/// A collection of CSS classes that can be used with the `classes` attribute in
+/// [`view!`](crate::view!).
+pub trait ClassList {
+    /// Calls `f` with every class in the collection.
+    fn for_each_class(&self, f: &mut dyn FnMut(&str));
+}
+
+impl<S: AsRef<str>> ClassList for Vec<S> {
+    fn for_each_class(&self, f: &mut dyn FnMut(&str)) {
+        self.iter().for_each(|class| f(class.as_ref()));
+    }
+}
+
+impl<S: AsRef<str>, H: BuildHasher> ClassList for HashSet<S, H> {
+    fn for_each_class(&self, f: &mut dyn FnMut(&str)) {
+        self.iter().for_each(|class| f(class.as_ref()));
+    }
+}
+
+impl<S: AsRef<str>> ClassList for BTreeSet<S> {
+    fn for_each_class(&self, f: &mut dyn FnMut(&str)) {
+        self.iter().for_each(|class| f(class.as_ref()));
+    }
+}
+
+/// Keeps the classes of `el` in sync with `classes`. Whenever `classes` changes, only the classes
+/// that were removed or added are updated. Other classes of `el` (e.g. set with the `class`
+/// attribute) are left untouched.
+///
+/// This is used to implement the `classes` attribute in [`view!`](crate::view!).
+pub fn bind_classes<G: GenericNode, C: ClassList + 'static>(el: &G, classes: &ReadSignal<C>) {
+    let el = el.clone();
+    let classes = classes.clone();
+    let mut current = Vec::new();
+    create_effect(move || {
+        let mut new = Vec::new();
+        classes.get().for_each_class(&mut |class| {
+            if !class.is_empty() && !new.iter().any(|c: &String| c == class) {
+                new.push(class.to_string());
+            }
+        });
+
+        let (removed, added) = diff_classes(&current, &new);
+        for class in removed {
+            el.remove_class(class);
+        }
+        for class in added {
+            el.add_class(class);
+        }
+        current = new;
+    });
+}
+
+/// Returns the classes that are in `old` but not in `new` and the classes that are in `new` but not
+/// in `old`.
+fn diff_classes<'a>(old: &'a [String], new: &'a [String]) -> (Vec<&'a str>, Vec<&'a str>) {
+    let removed = old
+        .iter()
+        .filter(|class| !new.contains(class))
+        .map(String::as_str)
+        .collect();
+    let added = new
+        .iter()
+        .filter(|class| !old.contains(class))
+        .map(String::as_str)
+        .collect();
+    (removed, added)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(classes: &[&str]) -> Vec<String> {
+        classes.iter().map(|class| class.to_string()).collect()
+    }
+
+    #[test]
+    fn diff_classes_is_minimal() {
+        let old = classes(&["a", "b", "c"]);
+        let new = classes(&["c", "b", "d"]);
+        assert_eq!(diff_classes(&old, &new), (vec!["a"], vec!["d"]));
+
+        assert_eq!(diff_classes(&old, &old), (vec![], vec![]));
+        assert_eq!(diff_classes(&[], &old), (vec![], vec!["a", "b", "c"]));
+    }
+}
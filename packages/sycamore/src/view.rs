@@ -54,6 +54,45 @@ impl<G: GenericNode> View<G> {
         Self::new_node(G::marker())
     }
 
+    /// Create a new [`View`] from a `Vec` of [`GenericNode`]s. Each node becomes a child of the
+    /// resulting fragment.
+    pub fn from_nodes(nodes: Vec<G>) -> Self {
+        Self::new_fragment(nodes.into_iter().map(Self::new_node).collect())
+    }
+
+    /// Appends `other` to the end of this [`View`]. If this [`View`] is not a fragment, it is
+    /// turned into a fragment containing the old view and `other`.
+    ///
+    /// This can be used to build up a [`View`] imperatively without the
+    /// [`view!`](crate::view!) macro.
+    ///
+    /// # Example
+    /// ```
+    /// use sycamore::prelude::*;
+    ///
+    /// # fn list<G: Html>() -> View<G> {
+    /// let mut items = View::new_fragment(Vec::new());
+    /// for i in 0..3 {
+    ///     items.append(view! { li { (i) } });
+    /// }
+    ///
+    /// view! {
+    ///     ul { (items) }
+    /// }
+    /// # }
+    /// ```
+    pub fn append(&mut self, other: View<G>) {
+        let fragment = match &self.inner {
+            ViewType::Fragment(fragment) => {
+                let mut fragment = fragment.to_vec();
+                fragment.push(other);
+                fragment
+            }
+            _ => vec![self.clone(), other],
+        };
+        *self = Self::new_fragment(fragment);
+    }
+
     pub fn as_node(&self) -> Option<&G> {
         if let ViewType::Node(v) = &self.inner {
             Some(v)
@@ -197,3 +236,82 @@ impl<T: fmt::Display + 'static, G: GenericNode> IntoView<G> for T {
         View::new_node(G::text_node(&t))
     }
 }
+
+/// Wrapper around an expression that is interpolated in [`view!`](crate::view!). Not intended for
+/// use by end-users.
+///
+/// `Option<View<G>>` cannot implement [`IntoView`] because it would overlap with the implementation
+/// for [`Display`](fmt::Display) types. Instead, `view!` calls `create_splice` on a reference to
+/// this wrapper. Method resolution picks [`SpliceOptionView`] for `Option<View<G>>`, which renders
+/// `None` as an empty view, and [`SpliceIntoView`] for all types implementing [`IntoView`].
+#[doc(hidden)]
+pub struct Splice<'a, T>(pub &'a T);
+
+#[doc(hidden)]
+pub trait SpliceOptionView<G: GenericNode> {
+    fn create_splice(&self) -> View<G>;
+}
+
+impl<G: GenericNode> SpliceOptionView<G> for Splice<'_, Option<View<G>>> {
+    fn create_splice(&self) -> View<G> {
+        self.0.clone().unwrap_or_else(View::empty)
+    }
+}
+
+#[doc(hidden)]
+pub trait SpliceIntoView<G: GenericNode> {
+    fn create_splice(&self) -> View<G>;
+}
+
+impl<G: GenericNode, T: IntoView<G>> SpliceIntoView<G> for &Splice<'_, T> {
+    fn create_splice(&self) -> View<G> {
+        self.0.create()
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn append_to_fragment() {
+        let html = sycamore::render_to_string(|| {
+            let mut view = View::new_fragment(Vec::new());
+            for i in 0..3 {
+                view.append(view! { li { (i) } });
+            }
+            assert_eq!(view.as_fragment().unwrap().len(), 3);
+            view! { ul { (view) } }
+        });
+        assert_eq!(html, "<ul><li>0</li><li>1</li><li>2</li></ul>");
+    }
+
+    #[test]
+    fn append_to_node() {
+        let html = sycamore::render_to_string(|| {
+            let mut view = view! { p { "first" } };
+            view.append(view! { p { "second" } });
+            assert!(view.is_fragment());
+            view
+        });
+        assert_eq!(html, "<p>first</p><p>second</p>");
+    }
+
+    #[test]
+    fn option_view() {
+        let render = |view: Option<View<SsrNode>>| {
+            sycamore::render_to_string(|| view! { p { "a" (view) "b" } })
+        };
+        assert_eq!(render(Some(view! { "x" })), "<p>axb</p>");
+        assert_eq!(render(None), "<p>a<!---->b</p>");
+    }
+
+    #[test]
+    fn from_nodes() {
+        let html = sycamore::render_to_string(|| {
+            View::from_nodes(vec![SsrNode::text_node("a"), SsrNode::element("br")])
+        });
+        assert_eq!(html, "a<br/>");
+    }
+}
@@ -0,0 +1,177 @@
+//! Virtualized lists that only render the rows that are visible.
+
+use std::hash::Hash;
+use std::ops::Range;
+
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+use crate::generic_node::GenericNode;
+use crate::prelude::*;
+use crate::reactive::map_keyed;
+
+/// Props for [`Virtualized`].
+pub struct VirtualizedProps<T, F, G: GenericNode, K, Key>
+where
+    F: Fn(T) -> View<G>,
+    K: Fn(&T) -> Key,
+    Key: Clone + Hash + Eq,
+    T: Clone + PartialEq + 'static,
+{
+    pub iterable: ReadSignal<Vec<T>>,
+    pub template: F,
+    pub key: K,
+    /// The height of every row in pixels. All rows must have the same height.
+    pub item_height: f64,
+    /// The height of the scroll container in pixels.
+    pub height: f64,
+    /// The number of rows that are rendered above and below the visible rows so that fast
+    /// scrolling does not reveal empty space.
+    pub buffer: usize,
+}
+
+/// Keyed iteration over a list that is too large to be rendered at once, like [`Keyed`].
+///
+/// The rows are rendered inside a scroll container of the given `height`. Only the rows that are
+/// visible in the container, plus `buffer` rows on each side, are rendered. Two spacer elements
+/// take up the height of the rows that are not rendered so that the scroll bar behaves as if all
+/// the rows were present. The visible rows are recomputed whenever the container is scrolled or
+/// `iterable` changes. Rows that stay visible are not re-rendered.
+///
+/// Every row must be exactly `item_height` pixels high. See [`visible_range`] for how the rendered
+/// rows are computed.
+///
+/// # Example
+/// A table with 100,000 rows:
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::virtualized::{Virtualized, VirtualizedProps};
+///
+/// let rows = Signal::new((0..100_000).collect::<Vec<u32>>());
+///
+/// let node = view! {
+///     Virtualized(VirtualizedProps {
+///         iterable: rows.handle(),
+///         template: |row| view! {
+///             div(style="height: 24px;") { "Row " (row) }
+///         },
+///         key: |row| *row,
+///         item_height: 24.0,
+///         height: 480.0,
+///         buffer: 10,
+///     })
+/// };
+/// # let _ : View<DomNode> = node;
+/// ```
+#[component(Virtualized<G>)]
+pub fn virtualized<T, F, K, Key>(props: VirtualizedProps<T, F, G, K, Key>) -> View<G>
+where
+    F: Fn(T) -> View<G> + 'static,
+    K: Fn(&T) -> Key + 'static,
+    Key: Clone + Hash + Eq + 'static,
+    T: Clone + Eq + 'static,
+{
+    let VirtualizedProps {
+        iterable,
+        template,
+        key,
+        item_height,
+        height,
+        buffer,
+    } = props;
+
+    let scroll_top = Signal::new(0.0);
+    let range = create_selector(cloned!((iterable, scroll_top) => move || {
+        visible_range(
+            *scroll_top.get(),
+            height,
+            item_height,
+            buffer,
+            iterable.get().len(),
+        )
+    }));
+    // `range` can lag behind `iterable` while both are being updated, so clamp it to the list.
+    let visible = create_memo(cloned!((iterable, range) => move || {
+        let items = iterable.get();
+        let range = range.get();
+        let end = range.end.min(items.len());
+        items[range.start.min(end)..end].to_vec()
+    }));
+    let top = create_memo(cloned!((range) => move || range.get().start as f64 * item_height));
+    let bottom = create_memo(cloned!((iterable, range) => move || {
+        iterable.get().len().saturating_sub(range.get().end) as f64 * item_height
+    }));
+
+    let on_scroll = move |event: web_sys::Event| {
+        let container = event
+            .current_target()
+            .unwrap_throw()
+            .unchecked_into::<web_sys::Element>();
+        scroll_top.set(container.scroll_top() as f64);
+    };
+
+    let mut mapped = map_keyed(visible, move |x| template(x.clone()), key);
+    let rows = View::new_dyn(move || View::new_fragment(mapped()));
+
+    view! {
+        div(style=format!("overflow-y: auto; height: {}px;", height), on:scroll=on_scroll) {
+            div(style=format!("height: {}px;", top.get()))
+            (rows)
+            div(style=format!("height: {}px;", bottom.get()))
+        }
+    }
+}
+
+/// Returns the range of the rows that [`Virtualized`] renders when its container is scrolled down
+/// by `scroll_top` pixels.
+///
+/// These are the rows that intersect the `viewport_height` pixels below `scroll_top`, plus
+/// `buffer` rows on each side. The range is clamped to the `len` rows of the list.
+///
+/// # Example
+/// ```
+/// use sycamore::virtualized::visible_range;
+///
+/// // Rows 50..60 are visible, rows 45..50 and 60..65 are the buffer.
+/// assert_eq!(visible_range(1000.0, 200.0, 20.0, 5, 1000), 45..65);
+/// ```
+pub fn visible_range(
+    scroll_top: f64,
+    viewport_height: f64,
+    item_height: f64,
+    buffer: usize,
+    len: usize,
+) -> Range<usize> {
+    if item_height <= 0.0 {
+        return 0..len;
+    }
+    let first = (scroll_top.max(0.0) / item_height).floor() as usize;
+    let last = ((scroll_top.max(0.0) + viewport_height) / item_height).ceil() as usize;
+
+    let start = first.saturating_sub(buffer).min(len);
+    let end = last.saturating_add(buffer).min(len);
+    start..end.max(start)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_at_top() {
+        assert_eq!(visible_range(0.0, 200.0, 20.0, 5, 1000), 0..15);
+    }
+
+    #[test]
+    fn range_after_scrolling() {
+        assert_eq!(visible_range(1000.0, 200.0, 20.0, 5, 1000), 45..65);
+        // A partially visible row is rendered.
+        assert_eq!(visible_range(1010.0, 200.0, 20.0, 0, 1000), 50..61);
+    }
+
+    #[test]
+    fn range_is_clamped_to_list() {
+        assert_eq!(visible_range(19_900.0, 200.0, 20.0, 5, 1000), 990..1000);
+        assert_eq!(visible_range(0.0, 200.0, 20.0, 5, 3), 0..3);
+        assert_eq!(visible_range(5000.0, 200.0, 20.0, 5, 0), 0..0);
+    }
+}
@@ -0,0 +1,1525 @@
+//! Reactive bindings to browser APIs.
+//!
+//! All the functions in this module require a browser environment. They should not be called when
+//! rendering with [`SsrNode`](crate::generic_node::SsrNode).
+
+use std::cell::{Cell, RefCell};
+use std::mem;
+use std::rc::Rc;
+
+use js_sys::{Array, Reflect};
+#[cfg(feature = "futures")]
+use js_sys::{Function, Promise};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+#[cfg(feature = "futures")]
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+#[cfg(feature = "serde")]
+use web_sys::WebSocket;
+use web_sys::{
+    AddEventListenerOptions, CustomEvent, DomRectReadOnly, Element, Event, EventTarget,
+    IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit, KeyboardEvent,
+    MediaQueryList, MediaQueryListEvent, MouseEvent, PointerEvent, Window,
+};
+
+use crate::generic_node::{DomNode, GenericNode};
+use crate::noderef::NodeRef;
+use crate::reactive::{cloned, create_effect, create_memo, on_cleanup, ReadSignal, Signal};
+use crate::utils::queue_microtask;
+
+/// The result of the last write to the clipboard. See [`use_clipboard`].
+///
+/// _This API requires the following crate features to be activated: `dom`, `futures`_
+#[cfg(feature = "futures")]
+pub type ClipboardResult = Option<Result<String, JsValue>>;
+
+/// Creates a reactive binding to the
+/// [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API).
+///
+/// Returns a [`ReadSignal`] and a function to copy text to the clipboard. Writing to the clipboard
+/// is asynchronous. Once it has completed, the signal is set to `Some(Ok(text))` with the copied
+/// text, or to `Some(Err(err))` if writing failed, e.g. because the user denied the permission or
+/// because the Clipboard API is not available. The signal is `None` until something has been
+/// copied.
+///
+/// _This API requires the following crate features to be activated: `dom`, `futures`_
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_clipboard;
+///
+/// #[component(CopyButton<G>)]
+/// fn copy_button(text: String) -> View<G> {
+///     let (copied, copy) = use_clipboard();
+///     let label = create_memo(move || match copied.get().as_ref() {
+///         Some(Ok(_)) => "Copied!",
+///         Some(Err(_)) => "Failed to copy",
+///         None => "Copy",
+///     });
+///
+///     view! {
+///         button(on:click=move |_| copy(text.clone())) { (label.get()) }
+///     }
+/// }
+/// ```
+#[cfg(feature = "futures")]
+pub fn use_clipboard() -> (ReadSignal<ClipboardResult>, impl Fn(String)) {
+    let state = Signal::new(None);
+
+    let copy = {
+        let state = state.clone();
+        move |text: String| {
+            let promise = write_text(&text);
+            let state = state.clone();
+            spawn_local(async move {
+                match promise {
+                    Ok(promise) => match JsFuture::from(promise).await {
+                        Ok(_) => state.set(Some(Ok(text))),
+                        Err(err) => state.set(Some(Err(err))),
+                    },
+                    Err(err) => state.set(Some(Err(err))),
+                }
+            });
+        }
+    };
+
+    (state.into_handle(), copy)
+}
+
+/// Calls `navigator.clipboard.writeText(text)`. The Clipboard API is accessed dynamically because
+/// it is still behind `web_sys_unstable_apis` in `web-sys`.
+#[cfg(feature = "futures")]
+fn write_text(text: &str) -> Result<Promise, JsValue> {
+    let navigator = web_sys::window().unwrap_throw().navigator();
+    let clipboard = Reflect::get(&navigator, &"clipboard".into())?;
+    if clipboard.is_undefined() {
+        return Err(JsValue::from_str("Clipboard API is not available"));
+    }
+    let write_text = Reflect::get(&clipboard, &"writeText".into())?.dyn_into::<Function>()?;
+    write_text
+        .call1(&clipboard, &JsValue::from_str(text))?
+        .dyn_into::<Promise>()
+}
+
+/// Creates a [`ReadSignal`] that is `true` whenever the
+/// [media query](https://developer.mozilla.org/en-US/docs/Web/CSS/Media_Queries/Using_media_queries)
+/// matches.
+///
+/// The signal is updated whenever the result of the media query changes. The listener is removed
+/// when the current reactive scope is dropped.
+///
+/// # Panics
+/// This function panics if `query` is not a valid media query.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_media_query;
+///
+/// #[component(Layout<G>)]
+/// fn layout(children: View<G>) -> View<G> {
+///     let is_mobile = use_media_query("(max-width: 600px)");
+///     let class = create_memo(move || if *is_mobile.get() { "stacked" } else { "sidebar" });
+///
+///     view! {
+///         div(class=class.get()) { (children.clone()) }
+///     }
+/// }
+/// ```
+pub fn use_media_query(query: &str) -> ReadSignal<bool> {
+    let media_query_list: MediaQueryList = web_sys::window()
+        .unwrap_throw()
+        .match_media(query)
+        .unwrap_throw()
+        .expect_throw("invalid media query");
+    let matches = media_query_list.matches();
+
+    create_signal_from_event(
+        &media_query_list,
+        "change",
+        |event| event.unchecked_into::<MediaQueryListEvent>().matches(),
+        matches,
+    )
+}
+
+/// Creates a [`ReadSignal`] that is `true` whenever the browser is online, as reported by
+/// [`navigator.onLine`](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/onLine).
+///
+/// The signal is updated on the `online` and `offline` events of the window. The listeners are
+/// removed when the current reactive scope is dropped.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_online;
+///
+/// #[component(OfflineBanner<G>)]
+/// fn offline_banner() -> View<G> {
+///     let online = use_online();
+///
+///     view! {
+///         (if *online.get() {
+///             view! {}
+///         } else {
+///             view! { div(class="banner") { "You are offline. Changes will be saved later." } }
+///         })
+///     }
+/// }
+/// ```
+pub fn use_online() -> ReadSignal<bool> {
+    let window = web_sys::window().unwrap_throw();
+    let online = Signal::new(window.navigator().on_line());
+
+    let listener = Closure::wrap(Box::new({
+        let online = online.clone();
+        move |event: Event| online.set(event.type_() == "online")
+    }) as Box<dyn FnMut(Event)>);
+    for event in ["online", "offline"] {
+        window
+            .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+            .unwrap_throw();
+    }
+
+    on_cleanup(move || {
+        for event in ["online", "offline"] {
+            window
+                .remove_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                .unwrap_throw();
+        }
+    });
+
+    online.into_handle()
+}
+
+/// Creates a [`ReadSignal`] that is `true` whenever the page is visible, as reported by the
+/// [Page Visibility API](https://developer.mozilla.org/en-US/docs/Web/API/Page_Visibility_API).
+///
+/// The page is hidden when the tab is in the background or the window is minimized. The signal is
+/// updated on the `visibilitychange` event of the document. The listener is removed when the
+/// current reactive scope is dropped.
+///
+/// # Example
+/// A clock that stops ticking while the page is hidden and resumes once it is visible again:
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::{use_interval, use_page_visible};
+///
+/// #[component(Ticker<G>)]
+/// fn ticker() -> View<G> {
+///     let visible = use_page_visible();
+///     let ticks = Signal::new(0);
+///     use_interval(1000, cloned!((ticks) => move || {
+///         if *visible.get_untracked() {
+///             ticks.set(*ticks.get_untracked() + 1);
+///         }
+///     }));
+///
+///     view! {
+///         p { "Seconds on this page: " (ticks.get()) }
+///     }
+/// }
+/// ```
+pub fn use_page_visible() -> ReadSignal<bool> {
+    let document = web_sys::window().unwrap_throw().document().unwrap_throw();
+    let visible = !document.hidden();
+
+    create_signal_from_event(
+        &document,
+        "visibilitychange",
+        {
+            let document = document.clone();
+            move |_| !document.hidden()
+        },
+        visible,
+    )
+}
+
+/// Creates a [`ReadSignal`] with the scroll position `(scrollX, scrollY)` of the window in pixels.
+///
+/// The position is read at most once per animation frame, no matter how many `scroll` events are
+/// fired in the meantime. The `scroll` listener is passive so that it never delays scrolling. The
+/// listener is removed when the current reactive scope is dropped.
+///
+/// # Example
+/// A "back to top" button that only appears after scrolling down:
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_window_scroll;
+///
+/// #[component(BackToTop<G>)]
+/// fn back_to_top() -> View<G> {
+///     let scroll = use_window_scroll();
+///     let visible = create_selector(move || scroll.get().1 > 400.0);
+///     let scroll_to_top = |_| web_sys::window().unwrap().scroll_to_with_x_and_y(0.0, 0.0);
+///
+///     view! {
+///         (if *visible.get() {
+///             view! { button(class="back-to-top", on:click=scroll_to_top) { "Back to top" } }
+///         } else {
+///             view! {}
+///         })
+///     }
+/// }
+/// ```
+pub fn use_window_scroll() -> ReadSignal<(f64, f64)> {
+    let window = web_sys::window().unwrap_throw();
+    let scroll_position = |window: &Window| {
+        (
+            window.scroll_x().unwrap_throw(),
+            window.scroll_y().unwrap_throw(),
+        )
+    };
+    let position = Signal::new(scroll_position(&window));
+
+    // The id of the animation frame that will read the scroll position, if one is requested.
+    let frame: Rc<Cell<Option<i32>>> = Rc::new(Cell::new(None));
+    let update = Closure::wrap(Box::new(cloned!((window, position, frame) => move || {
+        frame.set(None);
+        position.set(scroll_position(&window));
+    })) as Box<dyn FnMut()>);
+    let listener = Closure::wrap(Box::new(cloned!((window, frame) => move || {
+        if frame.get().is_none() {
+            let id = window
+                .request_animation_frame(update.as_ref().unchecked_ref())
+                .unwrap_throw();
+            frame.set(Some(id));
+        }
+    })) as Box<dyn FnMut()>);
+
+    // Set the option dynamically to stay compatible with the different versions of the
+    // `AddEventListenerOptions` setters in `web-sys`.
+    let options = AddEventListenerOptions::new();
+    Reflect::set(&options, &"passive".into(), &true.into()).unwrap_throw();
+    window
+        .add_event_listener_with_callback_and_add_event_listener_options(
+            "scroll",
+            listener.as_ref().unchecked_ref(),
+            &options,
+        )
+        .unwrap_throw();
+
+    on_cleanup(move || {
+        window
+            .remove_event_listener_with_callback("scroll", listener.as_ref().unchecked_ref())
+            .unwrap_throw();
+        if let Some(id) = frame.get() {
+            window.cancel_animation_frame(id).unwrap_throw();
+        }
+    });
+
+    position.into_handle()
+}
+
+/// Returns a [`ReadSignal`] with the `(x, y)` position of the mouse in pixels, relative to the
+/// viewport.
+///
+/// The position is updated by a `mousemove` listener on the window, at most once per animation
+/// frame. It is `(0.0, 0.0)` until the mouse is first moved. The listener is removed when the
+/// current reactive scope is dropped. For the position relative to an element, see
+/// [`use_mouse_relative_to`].
+///
+/// # Example
+/// A tooltip that follows the cursor:
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_mouse;
+///
+/// #[component(CursorTooltip<G>)]
+/// fn cursor_tooltip(text: String) -> View<G> {
+///     let mouse = use_mouse();
+///     let style = create_memo(move || {
+///         let (x, y) = *mouse.get();
+///         format!("position: fixed; left: {}px; top: {}px;", x + 12.0, y + 12.0)
+///     });
+///
+///     view! {
+///         div(class="tooltip", style=style.get()) { (text) }
+///     }
+/// }
+/// ```
+pub fn use_mouse() -> ReadSignal<(f64, f64)> {
+    track_mouse(|| (0.0, 0.0))
+}
+
+/// Like [`use_mouse`] but the position is relative to the top left corner of the border box of the
+/// element referenced by `node`.
+///
+/// The position of the element is read every time the position is updated, so it stays correct
+/// when the element moves or the page is scrolled. The position is relative to the viewport while
+/// the node is not set or is not a [`DomNode`].
+pub fn use_mouse_relative_to<G: GenericNode>(node: NodeRef<G>) -> ReadSignal<(f64, f64)> {
+    track_mouse(move || match node.try_get::<DomNode>() {
+        Some(node) => {
+            let rect = node
+                .inner_element()
+                .unchecked_into::<Element>()
+                .get_bounding_client_rect();
+            (rect.left(), rect.top())
+        }
+        None => (0.0, 0.0),
+    })
+}
+
+/// Implementation of [`use_mouse`] and [`use_mouse_relative_to`]. `origin` returns the position
+/// in the viewport that the mouse position is relative to.
+fn track_mouse(origin: impl Fn() -> (f64, f64) + 'static) -> ReadSignal<(f64, f64)> {
+    let window = web_sys::window().unwrap_throw();
+    let position = Signal::new((0.0, 0.0));
+    // The most recent position of the mouse in the viewport.
+    let client = Rc::new(Cell::new((0.0, 0.0)));
+
+    // The id of the animation frame that will update the position, if one is requested.
+    let frame: Rc<Cell<Option<i32>>> = Rc::new(Cell::new(None));
+    let update = Closure::wrap(Box::new(cloned!((position, client, frame) => move || {
+        frame.set(None);
+        let (x, y) = client.get();
+        let (left, top) = origin();
+        position.set((x - left, y - top));
+    })) as Box<dyn FnMut()>);
+    let listener = Closure::wrap(
+        Box::new(cloned!((window, frame) => move |event: MouseEvent| {
+            client.set((event.client_x() as f64, event.client_y() as f64));
+            if frame.get().is_none() {
+                let id = window
+                    .request_animation_frame(update.as_ref().unchecked_ref())
+                    .unwrap_throw();
+                frame.set(Some(id));
+            }
+        })) as Box<dyn FnMut(MouseEvent)>,
+    );
+
+    let options = AddEventListenerOptions::new();
+    Reflect::set(&options, &"passive".into(), &true.into()).unwrap_throw();
+    window
+        .add_event_listener_with_callback_and_add_event_listener_options(
+            "mousemove",
+            listener.as_ref().unchecked_ref(),
+            &options,
+        )
+        .unwrap_throw();
+
+    on_cleanup(move || {
+        window
+            .remove_event_listener_with_callback("mousemove", listener.as_ref().unchecked_ref())
+            .unwrap_throw();
+        if let Some(id) = frame.get() {
+            window.cancel_animation_frame(id).unwrap_throw();
+        }
+    });
+
+    position.into_handle()
+}
+
+/// A light or dark color scheme. See [`use_color_scheme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
+impl ColorScheme {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Light => "light",
+            Self::Dark => "dark",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "light" => Some(Self::Light),
+            "dark" => Some(Self::Dark),
+            _ => None,
+        }
+    }
+}
+
+/// The `localStorage` key under which [`use_color_scheme`] stores the chosen color scheme.
+const COLOR_SCHEME_KEY: &str = "sycamore-color-scheme";
+
+/// Creates a [`ReadSignal`] with the color scheme of the app and a function to change it.
+///
+/// Until a color scheme is chosen with the returned function, the signal follows the
+/// [`prefers-color-scheme`](https://developer.mozilla.org/en-US/docs/Web/CSS/@media/prefers-color-scheme)
+/// preference of the operating system and is updated when it changes. The chosen color scheme is
+/// stored in `localStorage` and restored the next time the app is loaded. If `localStorage` is not
+/// available, the choice is only kept until the page is reloaded.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::{use_color_scheme, ColorScheme};
+///
+/// #[component(App<G>)]
+/// fn app() -> View<G> {
+///     let (scheme, set_scheme) = use_color_scheme();
+///     let is_dark = create_memo(cloned!((scheme) => move || *scheme.get() == ColorScheme::Dark));
+///     let toggle = cloned!((is_dark) => move |_| {
+///         set_scheme(if *is_dark.get() { ColorScheme::Light } else { ColorScheme::Dark });
+///     });
+///     let class = cloned!((is_dark) => move || if *is_dark.get() { "app dark" } else { "app" });
+///     let label = move || if *is_dark.get() { "Light mode" } else { "Dark mode" };
+///
+///     view! {
+///         div(class=class()) {
+///             button(on:click=toggle) { (label()) }
+///         }
+///     }
+/// }
+/// ```
+pub fn use_color_scheme() -> (ReadSignal<ColorScheme>, impl Fn(ColorScheme)) {
+    let storage = web_sys::window()
+        .unwrap_throw()
+        .local_storage()
+        .ok()
+        .flatten();
+    let chosen = Signal::new(
+        storage
+            .as_ref()
+            .and_then(|storage| storage.get_item(COLOR_SCHEME_KEY).ok().flatten())
+            .and_then(|stored| ColorScheme::from_str(&stored)),
+    );
+
+    let prefers_dark = use_media_query("(prefers-color-scheme: dark)");
+    let scheme = create_memo(cloned!((chosen) => move || match *chosen.get() {
+        Some(scheme) => scheme,
+        None if *prefers_dark.get() => ColorScheme::Dark,
+        None => ColorScheme::Light,
+    }));
+
+    let set_scheme = move |scheme: ColorScheme| {
+        if let Some(storage) = &storage {
+            // Storing can fail, e.g. when the storage quota is exceeded. The choice is then only
+            // kept in memory.
+            let _ = storage.set_item(COLOR_SCHEME_KEY, scheme.as_str());
+        }
+        chosen.set(Some(scheme));
+    };
+
+    (scheme, set_scheme)
+}
+
+/// Reactively sets the title of the document to the value of `title`.
+///
+/// The title is updated whenever `title` changes. When the current reactive scope is dropped, the
+/// title is left as it is. This allows the next page to set its own title without the title
+/// flickering back to a previous value in between.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_title;
+///
+/// #[component(Page<G>)]
+/// fn page(name: ReadSignal<String>) -> View<G> {
+///     use_title(create_memo(cloned!((name) => move || format!("{} - My App", name.get()))));
+///
+///     view! {
+///         h1 { (name.get()) }
+///     }
+/// }
+/// ```
+pub fn use_title(title: ReadSignal<String>) {
+    let document = web_sys::window().unwrap_throw().document().unwrap_throw();
+    create_effect(move || document.set_title(&title.get()));
+}
+
+/// Calls `f` every `ms` milliseconds using
+/// [`setInterval`](https://developer.mozilla.org/en-US/docs/Web/API/setInterval).
+///
+/// The interval is cleared when the current reactive scope is dropped, e.g. when the component
+/// that called this function is unmounted.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_interval;
+///
+/// #[component(Clock<G>)]
+/// fn clock() -> View<G> {
+///     let now = Signal::new(js_sys::Date::new_0());
+///     use_interval(1000, cloned!((now) => move || now.set(js_sys::Date::new_0())));
+///
+///     view! {
+///         p { (now.get().to_locale_time_string("en-US")) }
+///     }
+/// }
+/// ```
+pub fn use_interval(ms: i32, f: impl FnMut() + 'static) {
+    let window = web_sys::window().unwrap_throw();
+    let callback = Closure::wrap(Box::new(f) as Box<dyn FnMut()>);
+    let handle = window
+        .set_interval_with_callback_and_timeout_and_arguments_0(
+            callback.as_ref().unchecked_ref(),
+            ms,
+        )
+        .unwrap_throw();
+
+    on_cleanup(move || {
+        window.clear_interval_with_handle(handle);
+        drop(callback);
+    });
+}
+
+/// Calls `f` once after `ms` milliseconds using
+/// [`setTimeout`](https://developer.mozilla.org/en-US/docs/Web/API/setTimeout).
+///
+/// The timeout is cleared when the current reactive scope is dropped before it has elapsed, so `f`
+/// is not called for a component that has already been unmounted.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_timeout;
+///
+/// #[component(Toast<G>)]
+/// fn toast(message: String) -> View<G> {
+///     let visible = Signal::new(true);
+///     use_timeout(3000, cloned!((visible) => move || visible.set(false)));
+///
+///     view! {
+///         (if *visible.get() {
+///             let message = message.clone();
+///             view! { div(class="toast") { (message) } }
+///         } else {
+///             view! {}
+///         })
+///     }
+/// }
+/// ```
+pub fn use_timeout(ms: i32, f: impl FnOnce() + 'static) {
+    let window = web_sys::window().unwrap_throw();
+    let callback = Closure::once(f);
+    let handle = window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(
+            callback.as_ref().unchecked_ref(),
+            ms,
+        )
+        .unwrap_throw();
+
+    on_cleanup(move || {
+        window.clear_timeout_with_handle(handle);
+        drop(callback);
+    });
+}
+
+/// Returns a signal that follows `is_loading`, but avoids flashing a loading indicator.
+///
+/// The returned signal only becomes `true` once `is_loading` has been `true` for `delay_ms`
+/// milliseconds, so fast loads never show the indicator at all. Once it has become `true`, it stays
+/// `true` for at least `min_show_ms` milliseconds, even if `is_loading` becomes `false` earlier. If
+/// `is_loading` becomes `true` again before the indicator has been hidden, the indicator simply
+/// stays visible.
+///
+/// The pending timers are cleared when the current reactive scope is dropped.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::create_delayed_loading;
+///
+/// #[component(UserList<G>)]
+/// fn user_list(is_loading: ReadSignal<bool>) -> View<G> {
+///     // Show the spinner after 200ms of loading, and then for at least 500ms.
+///     let show_spinner = create_delayed_loading(is_loading, 200, 500);
+///
+///     view! {
+///         (if *show_spinner.get() {
+///             view! { div(class="spinner") }
+///         } else {
+///             view! {}
+///         })
+///     }
+/// }
+/// ```
+pub fn create_delayed_loading(
+    is_loading: ReadSignal<bool>,
+    delay_ms: u32,
+    min_show_ms: u32,
+) -> ReadSignal<bool> {
+    let shown = Signal::new(false);
+    let shown_at = Rc::new(Cell::new(0.0));
+
+    // Each run of the effect disposes of the previous one, clearing the timeout it scheduled.
+    create_effect(cloned!((shown) => move || {
+        let is_loading = *is_loading.get();
+        let is_shown = *shown.get_untracked();
+        if is_loading && !is_shown {
+            use_timeout(
+                delay_ms as i32,
+                cloned!((shown, shown_at) => move || {
+                    shown_at.set(js_sys::Date::now());
+                    shown.set(true);
+                }),
+            );
+        } else if !is_loading && is_shown {
+            let remaining = min_show_ms as f64 - (js_sys::Date::now() - shown_at.get());
+            if remaining <= 0.0 {
+                shown.set(false);
+            } else {
+                use_timeout(
+                    remaining.ceil() as i32,
+                    cloned!((shown) => move || shown.set(false)),
+                );
+            }
+        }
+    }));
+
+    shown.into_handle()
+}
+
+/// Creates an effect that runs `f` at most once every `ms` milliseconds.
+///
+/// Like [`create_effect`], `f` is run immediately and the signals read inside it are tracked. When
+/// one of them changes less than `ms` milliseconds after the last run, `f` is not run right away.
+/// Instead, a trailing run is scheduled for when `ms` milliseconds have passed, so that the final
+/// state is never missed. Any number of changes in the meantime result in a single trailing run.
+///
+/// The pending trailing run is cancelled when the current reactive scope is dropped.
+///
+/// # Example
+/// Saving a draft at most once per second while the user is typing:
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::create_throttled_effect;
+///
+/// # fn save_draft(_: &str) {}
+/// #[component(Editor<G>)]
+/// fn editor() -> View<G> {
+///     let text = Signal::new(String::new());
+///     create_throttled_effect(1000, cloned!((text) => move || save_draft(&text.get())));
+///
+///     view! {
+///         textarea(bind:value=text)
+///     }
+/// }
+/// ```
+pub fn create_throttled_effect(ms: u32, mut f: impl FnMut() + 'static) {
+    let trigger = Signal::new(());
+    let last_run: Rc<Cell<Option<f64>>> = Rc::new(Cell::new(None));
+    let trailing = Rc::new(Cell::new(false));
+
+    // When `f` is not run, the effect only depends on `trigger` until the trailing run. Each run
+    // of the effect disposes of the previous one, clearing the timeout it scheduled.
+    create_effect(cloned!((trigger) => move || {
+        trigger.get();
+        let now = js_sys::Date::now();
+        let elapsed = last_run.get().map_or(f64::INFINITY, |last_run| now - last_run);
+        if trailing.replace(false) || elapsed >= ms as f64 {
+            last_run.set(Some(now));
+            f();
+        } else {
+            use_timeout(
+                (ms as f64 - elapsed).ceil() as i32,
+                cloned!((trigger, trailing) => move || {
+                    trailing.set(true);
+                    trigger.set(());
+                }),
+            );
+        }
+    }));
+}
+
+thread_local! {
+    /// The DOM reads and writes that are scheduled for the next animation frame. See
+    /// [`read_then_write`].
+    static DOM_QUEUE: RefCell<DomQueue> = RefCell::new(DomQueue::default());
+}
+
+#[derive(Default)]
+struct DomQueue {
+    reads: Vec<Box<dyn FnOnce()>>,
+    writes: Vec<Box<dyn FnOnce()>>,
+    scheduled: bool,
+}
+
+/// Schedules a DOM read and a DOM write in the next animation frame, avoiding layout thrashing.
+///
+/// Reading layout information, e.g. with `getBoundingClientRect`, after the DOM has been modified
+/// forces the browser to compute the layout synchronously. When several effects each read and then
+/// write, the layout is computed once for every effect. Instead, all the `read` callbacks that are
+/// scheduled for a frame run first, and all the `write` callbacks run afterwards, each receiving
+/// the result of its `read`. The layout is then only computed once per frame.
+///
+/// Reads and writes that are scheduled while the queue is being flushed run in the next frame.
+///
+/// # Example
+/// Sets every column to the width of its header without measuring in between two writes:
+/// ```no_run
+/// use sycamore::web::read_then_write;
+/// use wasm_bindgen::JsCast;
+/// use web_sys::HtmlElement;
+///
+/// fn align_columns(headers: &[HtmlElement], columns: &[HtmlElement]) {
+///     for (header, column) in headers.iter().zip(columns).map(|(h, c)| (h.clone(), c.clone())) {
+///         read_then_write(
+///             move || header.get_bounding_client_rect().width(),
+///             move |width| {
+///                 column
+///                     .style()
+///                     .set_property("width", &format!("{}px", width))
+///                     .unwrap();
+///             },
+///         );
+///     }
+/// }
+/// ```
+pub fn read_then_write<R: 'static>(
+    read: impl FnOnce() -> R + 'static,
+    write: impl FnOnce(R) + 'static,
+) {
+    let schedule = DOM_QUEUE.with(|queue| {
+        let mut queue = queue.borrow_mut();
+        queue.reads.push(Box::new(move || {
+            let value = read();
+            DOM_QUEUE.with(|queue| {
+                queue
+                    .borrow_mut()
+                    .writes
+                    .push(Box::new(move || write(value)));
+            });
+        }));
+        !mem::replace(&mut queue.scheduled, true)
+    });
+
+    if schedule {
+        let callback = Closure::once_into_js(flush_dom_queue);
+        web_sys::window()
+            .unwrap_throw()
+            .request_animation_frame(callback.unchecked_ref())
+            .unwrap_throw();
+    }
+}
+
+/// Runs all the reads and then all the writes that were scheduled with [`read_then_write`].
+fn flush_dom_queue() {
+    let reads = DOM_QUEUE.with(|queue| {
+        let mut queue = queue.borrow_mut();
+        queue.scheduled = false;
+        mem::take(&mut queue.reads)
+    });
+    // The borrow is released before running the callbacks because they can schedule more work.
+    for read in reads {
+        read();
+    }
+    let writes = DOM_QUEUE.with(|queue| mem::take(&mut queue.borrow_mut().writes));
+    for write in writes {
+        write();
+    }
+}
+
+/// A parsed keyboard shortcut. See [`use_hotkey`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hotkey {
+    ctrl: bool,
+    shift: bool,
+    alt: bool,
+    meta: bool,
+    /// The lowercased value of [`KeyboardEvent::key`] that triggers the hotkey.
+    key: String,
+}
+
+impl Hotkey {
+    fn parse(combo: &str) -> Option<Self> {
+        let mut hotkey = Hotkey {
+            ctrl: false,
+            shift: false,
+            alt: false,
+            meta: false,
+            key: String::new(),
+        };
+        let mut parts = combo.split('+').map(|part| part.trim().to_lowercase());
+        let key = parts.next_back()?;
+        for modifier in parts {
+            match modifier.as_str() {
+                "ctrl" | "control" => hotkey.ctrl = true,
+                "shift" => hotkey.shift = true,
+                "alt" | "option" => hotkey.alt = true,
+                "meta" | "cmd" | "super" => hotkey.meta = true,
+                _ => return None,
+            }
+        }
+        hotkey.key = match key.as_str() {
+            "" => return None,
+            "esc" => "escape".to_string(),
+            "space" => " ".to_string(),
+            _ => key,
+        };
+        Some(hotkey)
+    }
+
+    fn matches(&self, event: &KeyboardEvent) -> bool {
+        event.ctrl_key() == self.ctrl
+            && event.shift_key() == self.shift
+            && event.alt_key() == self.alt
+            && event.meta_key() == self.meta
+            && event.key().to_lowercase() == self.key
+    }
+}
+
+/// Calls `handler` whenever the keyboard shortcut `combo` is pressed anywhere in the window.
+///
+/// `combo` is a list of modifiers followed by a key, separated by `+`, such as `"ctrl+k"` or
+/// `"ctrl+shift+p"`. The modifiers are `ctrl`, `shift`, `alt` and `meta` (also `cmd`). The key is
+/// matched case-insensitively against [`KeyboardEvent::key`], with `esc` and `space` accepted as
+/// shorthands. Modifiers must match exactly, so `"ctrl+k"` does not fire for `Ctrl+Shift+K`.
+///
+/// The default action of a matching `keydown` event is prevented. The listener is removed when
+/// the current reactive scope is dropped. Calling `use_hotkey` several times registers several
+/// independent hotkeys.
+///
+/// # Panics
+/// This function panics if `combo` is not a valid keyboard shortcut.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_hotkey;
+///
+/// #[component(App<G>)]
+/// fn app() -> View<G> {
+///     let palette_open = Signal::new(false);
+///
+///     use_hotkey("ctrl+k", cloned!((palette_open) => move || palette_open.set(true)));
+///     use_hotkey("esc", cloned!((palette_open) => move || palette_open.set(false)));
+///
+///     view! {
+///         (if *palette_open.get() {
+///             view! { div(class="command-palette") { input(placeholder="Type a command...") } }
+///         } else {
+///             view! {}
+///         })
+///     }
+/// }
+/// ```
+pub fn use_hotkey(combo: &str, handler: impl Fn() + 'static) {
+    let hotkey = Hotkey::parse(combo).unwrap_or_else(|| panic!("invalid hotkey `{}`", combo));
+
+    let window = web_sys::window().unwrap_throw();
+    let listener = Closure::wrap(Box::new(move |event: KeyboardEvent| {
+        if hotkey.matches(&event) {
+            event.prevent_default();
+            handler();
+        }
+    }) as Box<dyn FnMut(KeyboardEvent)>);
+    window
+        .add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+        .unwrap_throw();
+
+    on_cleanup(move || {
+        window
+            .remove_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref())
+            .unwrap_throw();
+    });
+}
+
+/// Creates a [`ReadSignal`] that is updated every time `event` is fired on `target`.
+///
+/// The signal starts out with the value `initial`. Every time the event is fired, the signal is set
+/// to the value returned by `extract`. The event listener is removed when the current reactive
+/// scope is dropped.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::create_signal_from_event;
+///
+/// #[component(WindowSize<G>)]
+/// fn window_size() -> View<G> {
+///     let window = web_sys::window().unwrap();
+///     let size = |window: &web_sys::Window| {
+///         (
+///             window.inner_width().unwrap().as_f64().unwrap(),
+///             window.inner_height().unwrap().as_f64().unwrap(),
+///         )
+///     };
+///
+///     let initial = size(&window);
+///     let dimensions = create_signal_from_event(
+///         &window,
+///         "resize",
+///         move |_| size(&web_sys::window().unwrap()),
+///         initial,
+///     );
+///
+///     view! {
+///         p { (format!("{}x{}", dimensions.get().0, dimensions.get().1)) }
+///     }
+/// }
+/// ```
+pub fn create_signal_from_event<T: 'static>(
+    target: &EventTarget,
+    event: &str,
+    extract: impl Fn(Event) -> T + 'static,
+    initial: T,
+) -> ReadSignal<T> {
+    let signal = Signal::new(initial);
+
+    let listener = Closure::wrap(Box::new({
+        let signal = signal.clone();
+        move |event: Event| signal.set(extract(event))
+    }) as Box<dyn FnMut(Event)>);
+    target
+        .add_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+        .unwrap_throw();
+
+    let target = target.clone();
+    let event = event.to_string();
+    on_cleanup(move || {
+        target
+            .remove_event_listener_with_callback(&event, listener.as_ref().unchecked_ref())
+            .unwrap_throw();
+    });
+
+    signal.into_handle()
+}
+
+/// Dispatches a [`CustomEvent`] named `name` on `target`, carrying `detail`.
+///
+/// The event bubbles, so it can be handled by any ancestor of `target` using the `on:*` directive.
+/// This allows a component to notify its parents without receiving a callback as a prop. Use
+/// [`custom_event_detail`] in the handler to read `detail` back.
+///
+/// Returns `false` if a handler called `preventDefault` on the event and `true` otherwise.
+///
+/// # Example
+/// A list item notifies the list that it was selected:
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::{custom_event_detail, dispatch_custom_event};
+/// use wasm_bindgen::JsCast;
+///
+/// #[component(Item<G>)]
+/// fn item(id: u32) -> View<G> {
+///     view! {
+///         li(on:click=move |event: web_sys::Event| {
+///             let target = event.current_target().unwrap();
+///             dispatch_custom_event(&target, "select", id);
+///         }) { "Item " (id) }
+///     }
+/// }
+///
+/// #[component(List<G>)]
+/// fn list() -> View<G> {
+///     let selected = Signal::new(None);
+///
+///     view! {
+///         ul(on:select=cloned!((selected) => move |event| {
+///             selected.set(custom_event_detail(&event).as_f64().map(|id| id as u32));
+///         })) {
+///             Item(1)
+///             Item(2)
+///         }
+///         p { "Selected: " (format!("{:?}", selected.get())) }
+///     }
+/// }
+/// ```
+pub fn dispatch_custom_event(target: &EventTarget, name: &str, detail: impl Into<JsValue>) -> bool {
+    let init = js_sys::Object::new();
+    Reflect::set(&init, &"bubbles".into(), &true.into()).unwrap_throw();
+    Reflect::set(&init, &"cancelable".into(), &true.into()).unwrap_throw();
+    Reflect::set(&init, &"detail".into(), &detail.into()).unwrap_throw();
+    let event = CustomEvent::new_with_event_init_dict(name, init.unchecked_ref()).unwrap_throw();
+    target.dispatch_event(&event).unwrap_throw()
+}
+
+/// Returns the `detail` of a [`CustomEvent`], such as one dispatched by [`dispatch_custom_event`].
+///
+/// Returns `undefined` if `event` is not a [`CustomEvent`].
+pub fn custom_event_detail(event: &Event) -> JsValue {
+    match event.dyn_ref::<CustomEvent>() {
+        Some(event) => event.detail(),
+        None => JsValue::UNDEFINED,
+    }
+}
+
+/// Options for [`use_intersection_with`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntersectionOptions {
+    /// Margin around the viewport, using the same syntax as the CSS `margin` property. A positive
+    /// margin reports the node as visible before it actually scrolls into view. Defaults to
+    /// `"0px"`.
+    pub root_margin: String,
+    /// The ratio of the node that must be visible for it to be considered visible. `0.0` means
+    /// that a single visible pixel is enough and `1.0` means that the whole node must be visible.
+    /// Defaults to `0.0`.
+    pub threshold: f64,
+}
+
+impl Default for IntersectionOptions {
+    fn default() -> Self {
+        Self {
+            root_margin: "0px".to_string(),
+            threshold: 0.0,
+        }
+    }
+}
+
+/// Creates a [`ReadSignal`] that is `true` whenever the node referenced by `node` is visible in the
+/// viewport, using an
+/// [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/Intersection_Observer_API).
+///
+/// For configuring the root margin and threshold, see [`use_intersection_with`].
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_intersection;
+///
+/// #[component(InfiniteList<G>)]
+/// fn infinite_list() -> View<G> {
+///     let items = Signal::new((0..20).collect::<Vec<i32>>());
+///     let sentinel = NodeRef::new();
+///     let visible = use_intersection(sentinel.clone());
+///
+///     create_effect(cloned!((items) => move || {
+///         if *visible.get() {
+///             // Load more items when the end of the list becomes visible.
+///             let len = items.get_untracked().len() as i32;
+///             items.set(items.get_untracked().iter().copied().chain(len..len + 20).collect());
+///         }
+///     }));
+///
+///     view! {
+///         ul {
+///             Indexed(IndexedProps {
+///                 iterable: items.handle(),
+///                 template: |x| view! { li { (x) } },
+///             })
+///         }
+///         div(ref=sentinel)
+///     }
+/// }
+/// ```
+pub fn use_intersection<G: GenericNode>(node: NodeRef<G>) -> ReadSignal<bool> {
+    use_intersection_with(node, IntersectionOptions::default())
+}
+
+/// Like [`use_intersection`] but with additional options.
+///
+/// The node does not need to be set when this function is called. It is observed once the current
+/// rendering is done. Nodes that are not [`DomNode`]s are never observed. The observer is
+/// disconnected when the current reactive scope is dropped.
+///
+/// # Panics
+/// Panics if `options.root_margin` is not valid.
+pub fn use_intersection_with<G: GenericNode>(
+    node: NodeRef<G>,
+    options: IntersectionOptions,
+) -> ReadSignal<bool> {
+    let visible = Signal::new(false);
+
+    let callback = Closure::wrap(Box::new({
+        let visible = visible.clone();
+        move |entries: Array| {
+            // Only the most recent entry is relevant.
+            if let Some(entry) = entries.iter().last() {
+                visible.set(
+                    entry
+                        .unchecked_into::<IntersectionObserverEntry>()
+                        .is_intersecting(),
+                );
+            }
+        }
+    }) as Box<dyn FnMut(Array)>);
+
+    // Set the options dynamically to stay compatible with the different versions of the
+    // `IntersectionObserverInit` setters in `web-sys`.
+    let init = IntersectionObserverInit::new();
+    Reflect::set(
+        &init,
+        &"rootMargin".into(),
+        &JsValue::from_str(&options.root_margin),
+    )
+    .unwrap_throw();
+    Reflect::set(
+        &init,
+        &"threshold".into(),
+        &JsValue::from_f64(options.threshold),
+    )
+    .unwrap_throw();
+    let observer = IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+        .unwrap_throw();
+
+    with_mounted_element(node, {
+        let observer = observer.clone();
+        move |element| observer.observe(&element)
+    });
+
+    on_cleanup(move || {
+        observer.disconnect();
+        drop(callback);
+    });
+
+    visible.into_handle()
+}
+
+#[wasm_bindgen]
+extern "C" {
+    // `ResizeObserver` is still behind `web_sys_unstable_apis` in `web-sys`.
+    type ResizeObserver;
+
+    #[wasm_bindgen(constructor)]
+    fn new(callback: &js_sys::Function) -> ResizeObserver;
+
+    #[wasm_bindgen(method)]
+    fn observe(this: &ResizeObserver, target: &Element);
+
+    #[wasm_bindgen(method)]
+    fn disconnect(this: &ResizeObserver);
+
+    type ResizeObserverEntry;
+
+    #[wasm_bindgen(method, getter, js_name = contentRect)]
+    fn content_rect(this: &ResizeObserverEntry) -> DomRectReadOnly;
+}
+
+/// Creates a [`ReadSignal`] with the `(width, height)` of the content box of the node referenced
+/// by `node`, using a
+/// [`ResizeObserver`](https://developer.mozilla.org/en-US/docs/Web/API/Resize_Observer_API).
+///
+/// The size is `(0.0, 0.0)` until the node has been mounted and its size has been observed. Nodes
+/// that are not [`DomNode`]s are never observed. The observer is disconnected when the current
+/// reactive scope is dropped.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_element_size;
+///
+/// #[component(Chart<G>)]
+/// fn chart() -> View<G> {
+///     let container = NodeRef::new();
+///     let size = use_element_size(container.clone());
+///     let width = create_memo(cloned!((size) => move || size.get().0));
+///     let height = create_memo(move || size.get().1);
+///
+///     view! {
+///         div(ref=container, style="width: 100%; height: 400px;") {
+///             canvas(width=width.get(), height=height.get())
+///         }
+///     }
+/// }
+/// ```
+pub fn use_element_size<G: GenericNode>(node: NodeRef<G>) -> ReadSignal<(f64, f64)> {
+    let size = Signal::new((0.0, 0.0));
+
+    let callback = Closure::wrap(Box::new({
+        let size = size.clone();
+        move |entries: Array| {
+            // Only the most recent entry is relevant.
+            if let Some(entry) = entries.iter().last() {
+                let rect = entry.unchecked_into::<ResizeObserverEntry>().content_rect();
+                size.set((rect.width(), rect.height()));
+            }
+        }
+    }) as Box<dyn FnMut(Array)>);
+    let observer = Rc::new(ResizeObserver::new(callback.as_ref().unchecked_ref()));
+
+    with_mounted_element(node, {
+        let observer = Rc::clone(&observer);
+        move |element| observer.observe(&element)
+    });
+
+    on_cleanup(move || {
+        observer.disconnect();
+        drop(callback);
+    });
+
+    size.into_handle()
+}
+
+/// The axis along which a node can be dragged. See [`DraggableOptions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DragAxis {
+    /// The node can be dragged freely.
+    Both,
+    /// The node can only be dragged horizontally.
+    X,
+    /// The node can only be dragged vertically.
+    Y,
+}
+
+/// Options for [`use_draggable_with`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DraggableOptions {
+    /// Restricts dragging to one axis. The offset along the other axis stays `0.0`. Defaults to
+    /// [`DragAxis::Both`].
+    pub axis: DragAxis,
+}
+
+impl Default for DraggableOptions {
+    fn default() -> Self {
+        Self {
+            axis: DragAxis::Both,
+        }
+    }
+}
+
+/// A drag that is in progress. See [`use_draggable_with`].
+#[derive(Clone, Copy)]
+struct Drag {
+    pointer_id: i32,
+    /// The pointer position at which the offset would be `(0.0, 0.0)`.
+    origin: (f64, f64),
+}
+
+/// The pointer events that are handled by [`use_draggable_with`].
+const DRAG_EVENTS: [&str; 4] = ["pointerdown", "pointermove", "pointerup", "pointercancel"];
+
+/// Makes the node referenced by `node` draggable with a mouse, pen or touch. Returns a
+/// [`ReadSignal`] with the `(x, y)` offset in pixels that the node has been dragged by in total.
+///
+/// The node is not moved automatically. Use the offset to position it, for example with a CSS
+/// `transform`. For restricting dragging to one axis, see [`use_draggable_with`].
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::use_draggable;
+///
+/// #[component(DraggableBox<G>)]
+/// fn draggable_box() -> View<G> {
+///     let node = NodeRef::new();
+///     let offset = use_draggable(node.clone());
+///     let style = create_memo(move || {
+///         let (x, y) = *offset.get();
+///         format!("transform: translate({}px, {}px); touch-action: none;", x, y)
+///     });
+///
+///     view! {
+///         div(ref=node, class="box", style=style.get()) { "Drag me" }
+///     }
+/// }
+/// ```
+pub fn use_draggable<G: GenericNode>(node: NodeRef<G>) -> ReadSignal<(f64, f64)> {
+    use_draggable_with(node, DraggableOptions::default())
+}
+
+/// Like [`use_draggable`] but with additional options.
+///
+/// A drag starts with a `pointerdown` event on the node. The pointer is then captured so that the
+/// node keeps receiving `pointermove` events when the pointer leaves it, until the drag ends with a
+/// `pointerup` or `pointercancel` event. Only the pointer that started the drag is tracked.
+///
+/// The node does not need to be set when this function is called. The listeners are added once the
+/// current rendering is done and removed when the current reactive scope is dropped. Nodes that are
+/// not [`DomNode`]s are never made draggable.
+pub fn use_draggable_with<G: GenericNode>(
+    node: NodeRef<G>,
+    options: DraggableOptions,
+) -> ReadSignal<(f64, f64)> {
+    let offset = Signal::new((0.0, 0.0));
+    let drag: Rc<Cell<Option<Drag>>> = Rc::new(Cell::new(None));
+
+    let listener = Closure::wrap(Box::new({
+        let offset = offset.clone();
+        move |event: PointerEvent| {
+            let pointer = (event.client_x() as f64, event.client_y() as f64);
+            let target = event.current_target().unwrap_throw();
+            let element = target.unchecked_ref::<Element>();
+            match (event.type_().as_str(), drag.get()) {
+                ("pointerdown", None) => {
+                    let (x, y) = *offset.get_untracked();
+                    drag.set(Some(Drag {
+                        pointer_id: event.pointer_id(),
+                        origin: (pointer.0 - x, pointer.1 - y),
+                    }));
+                    // Capturing fails if the pointer is no longer active, which is not an error.
+                    let _ = element.set_pointer_capture(event.pointer_id());
+                }
+                ("pointermove", Some(Drag { pointer_id, origin }))
+                    if pointer_id == event.pointer_id() =>
+                {
+                    let (x, y) = *offset.get_untracked();
+                    offset.set(match options.axis {
+                        DragAxis::Both => (pointer.0 - origin.0, pointer.1 - origin.1),
+                        DragAxis::X => (pointer.0 - origin.0, y),
+                        DragAxis::Y => (x, pointer.1 - origin.1),
+                    });
+                }
+                ("pointerup" | "pointercancel", Some(Drag { pointer_id, .. }))
+                    if pointer_id == event.pointer_id() =>
+                {
+                    drag.set(None);
+                    let _ = element.release_pointer_capture(pointer_id);
+                }
+                _ => {}
+            }
+        }
+    }) as Box<dyn FnMut(PointerEvent)>);
+
+    let mounted: Rc<RefCell<Option<Element>>> = Rc::new(RefCell::new(None));
+    with_mounted_element(node, {
+        let mounted = Rc::clone(&mounted);
+        let listener = listener.as_ref().clone();
+        move |element| {
+            for event in DRAG_EVENTS {
+                element
+                    .add_event_listener_with_callback(event, listener.unchecked_ref())
+                    .unwrap_throw();
+            }
+            *mounted.borrow_mut() = Some(element);
+        }
+    });
+
+    on_cleanup(move || {
+        if let Some(element) = mounted.borrow_mut().take() {
+            for event in DRAG_EVENTS {
+                element
+                    .remove_event_listener_with_callback(event, listener.as_ref().unchecked_ref())
+                    .unwrap_throw();
+            }
+        }
+        drop(listener);
+    });
+
+    offset.into_handle()
+}
+
+/// What to do with values that are set while the `WebSocket` is not open. See
+/// [`SocketSyncOptions`].
+///
+/// _This API requires the following crate features to be activated: `dom`, `serde`_
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotOpen {
+    /// Sends the latest value once the socket has opened. Values that were set in between are
+    /// skipped.
+    Buffer,
+    /// Drops the value.
+    Drop,
+}
+
+/// Options for [`sync_signal_to_socket_with`].
+///
+/// _This API requires the following crate features to be activated: `dom`, `serde`_
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SocketSyncOptions {
+    /// What to do with values that are set while the socket is still connecting. Values that are
+    /// set once the socket is closing or closed are always dropped. Defaults to
+    /// [`NotOpen::Buffer`].
+    pub not_open: NotOpen,
+}
+
+#[cfg(feature = "serde")]
+impl Default for SocketSyncOptions {
+    fn default() -> Self {
+        Self {
+            not_open: NotOpen::Buffer,
+        }
+    }
+}
+
+/// Sends the value of `signal` over `ws` as JSON every time it changes, including the current value
+/// when this function is called.
+///
+/// Values that are set while the socket is still connecting are sent once it has opened. For
+/// dropping them instead, see [`sync_signal_to_socket_with`]. Reconnecting after the socket has
+/// been closed is not handled. Syncing stops when the current reactive scope is dropped.
+///
+/// _This API requires the following crate features to be activated: `dom`, `serde`_
+///
+/// # Panics
+/// Panics if the value cannot be serialized to JSON.
+///
+/// # Example
+/// ```no_run
+/// use sycamore::prelude::*;
+/// use sycamore::web::sync_signal_to_socket;
+/// use web_sys::WebSocket;
+///
+/// #[component(Editor<G>)]
+/// fn editor() -> View<G> {
+///     // The paragraphs of a document that is shared with other users.
+///     let paragraphs = Signal::new(vec![String::new()]);
+///     let socket = WebSocket::new("wss://example.com/documents/42").unwrap();
+///     sync_signal_to_socket(paragraphs.handle(), socket);
+///
+///     let text = Signal::new(String::new());
+///     create_effect(cloned!((paragraphs, text) => move || {
+///         paragraphs.set(text.get().split("\n\n").map(String::from).collect());
+///     }));
+///
+///     view! {
+///         textarea(bind:value=text)
+///     }
+/// }
+/// ```
+#[cfg(feature = "serde")]
+pub fn sync_signal_to_socket<T>(signal: ReadSignal<T>, ws: WebSocket)
+where
+    T: serde_crate::Serialize + 'static,
+{
+    sync_signal_to_socket_with(signal, ws, SocketSyncOptions::default());
+}
+
+/// Like [`sync_signal_to_socket`] but with additional options.
+///
+/// _This API requires the following crate features to be activated: `dom`, `serde`_
+#[cfg(feature = "serde")]
+pub fn sync_signal_to_socket_with<T>(
+    signal: ReadSignal<T>,
+    ws: WebSocket,
+    options: SocketSyncOptions,
+) where
+    T: serde_crate::Serialize + 'static,
+{
+    // The latest value that could not be sent because the socket was still connecting.
+    let pending: Rc<RefCell<Option<String>>> = Rc::new(RefCell::new(None));
+
+    let on_open = Closure::wrap(Box::new({
+        let ws = ws.clone();
+        let pending = Rc::clone(&pending);
+        move |_: Event| {
+            if let Some(json) = pending.borrow_mut().take() {
+                let _ = ws.send_with_str(&json);
+            }
+        }
+    }) as Box<dyn FnMut(Event)>);
+    ws.add_event_listener_with_callback("open", on_open.as_ref().unchecked_ref())
+        .unwrap_throw();
+
+    create_effect({
+        let ws = ws.clone();
+        move || {
+            let json = serde_json::to_string(&*signal.get())
+                .expect_throw("failed to serialize signal value");
+            match ws.ready_state() {
+                WebSocket::OPEN => {
+                    let _ = ws.send_with_str(&json);
+                }
+                WebSocket::CONNECTING if options.not_open == NotOpen::Buffer => {
+                    *pending.borrow_mut() = Some(json);
+                }
+                _ => {}
+            }
+        }
+    });
+
+    on_cleanup(move || {
+        ws.remove_event_listener_with_callback("open", on_open.as_ref().unchecked_ref())
+            .unwrap_throw();
+    });
+}
+
+/// Calls `f` with the element referenced by `node` once the current rendering is done. The node is
+/// usually not set when a hook is called because the view has not been created yet.
+///
+/// `f` is not called if the current reactive scope is dropped in the meantime or if the node is not
+/// a [`DomNode`].
+fn with_mounted_element<G: GenericNode>(node: NodeRef<G>, f: impl FnOnce(Element) + 'static) {
+    let disposed = Rc::new(Cell::new(false));
+    on_cleanup(cloned!((disposed) => move || disposed.set(true)));
+
+    queue_microtask(move || {
+        if disposed.get() {
+            return;
+        }
+        if let Some(node) = node.try_get::<DomNode>() {
+            f(node.inner_element().unchecked_into());
+        }
+    });
+}
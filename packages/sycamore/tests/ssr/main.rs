@@ -43,6 +43,26 @@ fn reactive_text_with_siblings() {
     assert_eq!(sycamore::render_to_string(|| node), "<p>before1after</p>");
 }
 
+#[test]
+fn reactive_derived_text() {
+    let first = Signal::new("John");
+    let last = Signal::new("Doe");
+
+    let full = derived!((first, last) => format!("{} {}", first.get(), last.get()));
+
+    let node = view! {
+        p { (full.get()) }
+    };
+
+    assert_eq!(
+        sycamore::render_to_string(cloned!((node) => move || node)),
+        "<p>John Doe</p>"
+    );
+
+    last.set("Smith");
+    assert_eq!(sycamore::render_to_string(|| node), "<p>John Smith</p>");
+}
+
 #[test]
 fn self_closing_tag() {
     let node = view! {
@@ -99,6 +119,137 @@ fn indexed() {
     assert_eq!(actual, "<ul><li>2</li><li>3</li></ul>");
 }
 
+#[test]
+fn intersperse() {
+    let path = Signal::new(vec!["a", "b"]);
+
+    let node = cloned!((path) => view! {
+        nav {
+            Intersperse(IntersperseProps {
+                iterable: path.handle(),
+                template: |segment| view! {
+                    span { (segment) }
+                },
+                key: |segment| *segment,
+                separator: || view! { "/" },
+            })
+        }
+    });
+
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<nav><span>a</span>/<span>b</span></nav>");
+
+    path.set(vec!["a", "b", "c"]);
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(
+        actual,
+        "<nav><span>a</span>/<span>b</span>/<span>c</span></nav>"
+    );
+
+    path.set(vec!["c"]);
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<nav><span>c</span></nav>");
+
+    path.set(vec![]);
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<nav></nav>");
+}
+
+#[test]
+fn boolean_attributes() {
+    let disabled = Signal::new(true);
+
+    let node = cloned!((disabled) => view! {
+        button(disabled=*disabled.get(), required=false)
+    });
+
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<button disabled=\"\"></button>");
+
+    disabled.set(false);
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<button></button>");
+}
+
+#[test]
+fn autofocus_attribute_is_reactive() {
+    let autofocus = Signal::new(true);
+
+    let node = cloned!((autofocus) => view! {
+        input(autofocus=*autofocus.get())
+    });
+
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<input autofocus=\"\"/>");
+
+    autofocus.set(false);
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<input/>");
+}
+
+#[test]
+fn classes() {
+    let classes = Signal::new(vec!["active"]);
+
+    let node = cloned!((classes) => view! {
+        div(classes=classes)
+    });
+
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<div class=\"active\"></div>");
+
+    classes.set(vec!["disabled"]);
+    let actual = sycamore::render_to_string(|| node.clone());
+    assert_eq!(actual, "<div class=\"disabled\"></div>");
+}
+
+#[test]
+fn style_properties() {
+    let progress = Signal::new(0.25);
+
+    let node = cloned!((progress) => view! {
+        div(style="color: red;", style:--progress=progress.get())
+    });
+    assert_eq!(
+        sycamore::render_to_string(cloned!((node) => move || node)),
+        "<div style=\"color: red; --progress: 0.25;\"></div>"
+    );
+
+    progress.set(0.5);
+    assert_eq!(
+        sycamore::render_to_string(|| node),
+        "<div style=\"color: red; --progress: 0.5;\"></div>"
+    );
+}
+
+#[test]
+fn class_toggles() {
+    let active = Signal::new(false);
+    let primary = Signal::new(true);
+
+    let node = cloned!((active, primary) => view! {
+        button(class=("btn", active => *active.get(), "btn-primary" => *primary.get()))
+    });
+    // Classes rendered by `SsrNode` are unordered.
+    let classes = || {
+        let html = sycamore::render_to_string(|| node.clone());
+        let class = html
+            .trim_start_matches("<button class=\"")
+            .trim_end_matches("\"></button>");
+        let mut classes: Vec<_> = class.split(' ').map(ToString::to_string).collect();
+        classes.sort();
+        classes
+    };
+
+    assert_eq!(classes(), ["btn", "btn-primary"]);
+
+    active.set(true);
+    assert_eq!(classes(), ["active", "btn", "btn-primary"]);
+
+    primary.set(false);
+    assert_eq!(classes(), ["active", "btn"]);
+}
+
 #[test]
 fn bind() {
     let signal = Signal::new(String::new());
@@ -110,3 +261,140 @@ fn bind() {
     let actual = sycamore::render_to_string(|| node);
     assert_eq!(actual, "<input/>");
 }
+
+#[test]
+fn conditional_only_creates_taken_branch() {
+    let visible = Signal::new(false);
+    let runs = Signal::new(Vec::new());
+    let disposed = Signal::new(Vec::new());
+
+    let branch = |name: &'static str, runs: Signal<Vec<_>>, disposed: Signal<Vec<_>>| {
+        create_effect(move || runs.push(name));
+        on_cleanup(move || disposed.push(name));
+        view! { (name) }
+    };
+
+    let node = cloned!((visible, runs, disposed) => view! {
+        div {
+            (if *visible.get() {
+                branch("then", runs.clone(), disposed.clone())
+            } else {
+                branch("else", runs.clone(), disposed.clone())
+            })
+        }
+    });
+
+    assert_eq!(
+        sycamore::render_to_string(cloned!((node) => move || node)),
+        "<div>else</div>"
+    );
+    // The effect in the branch that is not taken never runs.
+    assert_eq!(*runs.get(), vec!["else"]);
+
+    // Switching branches disposes of the previous branch.
+    visible.set(true);
+    assert_eq!(sycamore::render_to_string(|| node), "<div>then</div>");
+    assert_eq!(*runs.get(), vec!["else", "then"]);
+    assert_eq!(*disposed.get(), vec!["else"]);
+}
+
+#[derive(Clone)]
+struct BadgeProps {
+    label: String,
+    class: String,
+}
+
+#[component(Badge<G>)]
+fn badge(props: BadgeProps) -> View<G> {
+    let BadgeProps { label, class } = props;
+    view! {
+        span(class=class) { (label) }
+    }
+}
+
+#[test]
+fn props_spread_overrides_base() {
+    let base = BadgeProps {
+        label: "Base".to_string(),
+        class: "badge".to_string(),
+    };
+
+    let node = view! { Badge(label: "Explicit".to_string(), ..base.clone()) };
+    assert_eq!(
+        sycamore::render_to_string(|| node),
+        "<span class=\"badge\">Explicit</span>"
+    );
+
+    // The explicit fields are assigned in order, so the last one wins.
+    let node = view! { Badge(label: "First".to_string(), label: "Last".to_string(), ..base) };
+    assert_eq!(
+        sycamore::render_to_string(|| node),
+        "<span class=\"badge\">Last</span>"
+    );
+}
+
+#[derive(Slots)]
+struct DialogProps<G: GenericNode> {
+    title: String,
+    #[slot]
+    header: View<G>,
+    #[slot]
+    body: View<G>,
+    #[slot]
+    footer: View<G>,
+}
+
+impl<G: GenericNode> Default for DialogProps<G> {
+    fn default() -> Self {
+        Self {
+            title: String::new(),
+            header: View::empty(),
+            body: View::empty(),
+            footer: View::empty(),
+        }
+    }
+}
+
+#[component(Dialog<G>)]
+fn dialog(props: DialogProps<G>) -> View<G> {
+    let DialogProps {
+        title,
+        header,
+        body,
+        footer,
+    } = props;
+    view! {
+        div(title=title) {
+            header { (header) }
+            main { (body) }
+            footer { (footer) }
+        }
+    }
+}
+
+#[test]
+fn named_slots() {
+    let node = view! {
+        Dialog(title: "Confirm".to_string(), ..Default::default()) {
+            Slot(name="footer") { button { "OK" } }
+            Slot(name="header") { h1 { "Delete?" } }
+            Slot(name="body") { "This " "cannot be undone." }
+        }
+    };
+    assert_eq!(
+        sycamore::render_to_string(|| node),
+        "<div title=\"Confirm\"><header><h1>Delete?</h1></header>\
+        <main>This cannot be undone.</main><footer><button>OK</button></footer></div>"
+    );
+
+    // Slots that are not filled in keep their initial value.
+    let node = view! {
+        Dialog(DialogProps::default()) {
+            Slot(name="body") { "Body" }
+        }
+    };
+    assert_eq!(
+        sycamore::render_to_string(|| node),
+        "<div title=\"\"><header><!----></header><main>Body</main><footer><!----></footer></div>"
+    );
+}
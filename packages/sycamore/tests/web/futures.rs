@@ -0,0 +1,52 @@
+use futures_channel::mpsc::unbounded;
+use sycamore::futures::{create_signal_from_receiver, flush_effects};
+use sycamore::reactive::{pause_reactivity, pending_effect_count, resume_reactivity};
+
+use super::*;
+
+#[wasm_bindgen_test]
+async fn signal_from_receiver() {
+    let (tx, rx) = unbounded();
+
+    let mut signal = None;
+    let root = create_root(|| {
+        signal = Some(create_signal_from_receiver(0, rx));
+    });
+    let signal = signal.unwrap();
+    assert_eq!(*signal.get(), 0);
+
+    tx.unbounded_send(1).unwrap();
+    tx.unbounded_send(2).unwrap();
+    sleep(10).await;
+    assert_eq!(*signal.get(), 2);
+
+    drop(root);
+    sleep(10).await;
+    assert!(tx.unbounded_send(3).is_err());
+    assert_eq!(*signal.get(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn flush_effects_waits_for_paused_updates() {
+    let count = Signal::new(0);
+    let node = cloned!((count) => view! {
+        p { (count.get()) }
+    });
+    sycamore::render_to(|| node, &test_container());
+    let p = document().query_selector("p").unwrap().unwrap();
+
+    pause_reactivity();
+    count.set(1);
+    assert_eq!(pending_effect_count(), 1);
+    assert_eq!(p.text_content().unwrap(), "0");
+
+    let window = window();
+    let resume = wasm_bindgen::closure::Closure::once_into_js(resume_reactivity);
+    window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(resume.unchecked_ref(), 10)
+        .unwrap();
+
+    flush_effects().await;
+    assert_eq!(pending_effect_count(), 0);
+    assert_eq!(p.text_content().unwrap(), "1");
+}
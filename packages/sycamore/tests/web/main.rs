@@ -1,12 +1,17 @@
 pub mod cleanup;
+#[cfg(feature = "futures")]
+pub mod futures;
 pub mod keyed;
+pub mod motion;
 pub mod non_keyed;
 pub mod portal;
 pub mod reconcile;
 pub mod render;
+pub mod web;
 
 use sycamore::prelude::*;
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
 use wasm_bindgen_test::*;
 use web_sys::{Document, Event, HtmlElement, HtmlInputElement, Node, Window};
 
@@ -20,6 +25,16 @@ fn document() -> Document {
     window().document().unwrap()
 }
 
+/// Waits for `ms` milliseconds.
+async fn sleep(ms: i32) {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        window()
+            .set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            .unwrap();
+    });
+    JsFuture::from(promise).await.unwrap();
+}
+
 /// Returns a [`Node`] referencing the test container with the contents cleared.
 fn test_container() -> Node {
     if document()
@@ -100,6 +115,20 @@ fn hello_world_noderef() {
     );
 }
 
+#[wasm_bindgen_test]
+fn ref_callback() {
+    let tag = Signal::new(None);
+
+    let node = cloned!((tag) => view! {
+        canvas(ref=move |node: DomNode| {
+            tag.set(Some(node.unchecked_into::<web_sys::Element>().tag_name()));
+        })
+    });
+    sycamore::render_to(|| node, &test_container());
+
+    assert_eq!(tag.get().as_deref(), Some("CANVAS"));
+}
+
 #[wasm_bindgen_test]
 fn interpolation() {
     let text = "Hello Sycamore!";
@@ -342,6 +371,279 @@ fn two_way_bind_to_props() {
     assert_eq!(value.get().as_str(), "def");
 }
 
+#[wasm_bindgen_test]
+fn two_way_bind_text_content() {
+    let note = Signal::new("abc".to_string());
+
+    sycamore::render_to(
+        cloned!((note) => move || view! {
+            div(contenteditable=true, bind:textContent=note)
+        }),
+        &test_container(),
+    );
+
+    let div = document()
+        .query_selector("div[contenteditable]")
+        .unwrap()
+        .unwrap();
+    assert_eq!(div.text_content().unwrap(), "abc");
+
+    note.set("def".to_string());
+    assert_eq!(div.text_content().unwrap(), "def");
+
+    // Text typed by the user is not written back to the element, which would move the cursor.
+    let typed = document().create_text_node("typed");
+    div.set_text_content(None);
+    div.append_child(&typed).unwrap();
+    div.dispatch_event(&Event::new("input").unwrap()).unwrap();
+    assert_eq!(note.get().as_str(), "typed");
+    assert!(div.first_child().unwrap().is_same_node(Some(&typed)));
+}
+
+#[wasm_bindgen_test]
+fn multiple_event_handlers_on_same_event() {
+    let calls = Signal::new(Vec::new());
+
+    sycamore::render_to(
+        cloned!((calls) => move || view! {
+            button(
+                on:click=cloned!((calls) => move |_| {
+                    calls.set(calls.get().iter().copied().chain(Some(1)).collect());
+                }),
+                on:click=move |_| {
+                    calls.set(calls.get().iter().copied().chain(Some(2)).collect());
+                },
+            )
+        }),
+        &test_container(),
+    );
+
+    let button = document()
+        .query_selector("button")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    button.click();
+
+    // Both handlers run, in declaration order.
+    assert_eq!(*calls.get(), vec![1, 2]);
+}
+
+#[wasm_bindgen_test]
+fn two_way_bind_ignores_input_while_composing() {
+    let value = Signal::new(String::new());
+    let counter = Signal::new(0);
+
+    create_effect(cloned!((value, counter) => move || {
+        value.get(); // subscribe to value
+        counter.set(*counter.get_untracked() + 1);
+    }));
+    assert_eq!(*counter.get(), 1);
+
+    sycamore::render_to(
+        cloned!((value) => move || view! {
+            input(bind:value=value)
+        }),
+        &test_container(),
+    );
+
+    let input = document()
+        .query_selector("input")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlInputElement>();
+
+    input
+        .dispatch_event(&Event::new("compositionstart").unwrap())
+        .unwrap();
+    for intermediate in ["n", "ni", "你"] {
+        js_sys::Reflect::set(&input, &"value".into(), &intermediate.into()).unwrap();
+        input.dispatch_event(&input_event(true)).unwrap();
+    }
+    assert_eq!(value.get().as_str(), "");
+    assert_eq!(*counter.get(), 1);
+
+    input
+        .dispatch_event(&Event::new("compositionend").unwrap())
+        .unwrap();
+    // Firefox fires a final `input` event after `compositionend`.
+    input.dispatch_event(&input_event(false)).unwrap();
+    assert_eq!(value.get().as_str(), "你");
+    assert_eq!(*counter.get(), 2); // signal only updated once
+}
+
+/// Creates an `input` event with the `isComposing` flag set to `is_composing`.
+fn input_event(is_composing: bool) -> Event {
+    let init = js_sys::Object::new();
+    js_sys::Reflect::set(&init, &"isComposing".into(), &is_composing.into()).unwrap();
+    let constructor =
+        js_sys::Reflect::get(&web_sys::window().unwrap(), &"InputEvent".into()).unwrap();
+    js_sys::Reflect::construct(
+        constructor.unchecked_ref::<js_sys::Function>(),
+        &js_sys::Array::of2(&"input".into(), &init),
+    )
+    .unwrap()
+    .unchecked_into()
+}
+
+#[wasm_bindgen_test]
+async fn autofocus() {
+    let show_modal = Signal::new(false);
+
+    sycamore::render_to(
+        cloned!((show_modal) => move || view! {
+            (if *show_modal.get() {
+                view! {
+                    div(class="modal") {
+                        input(id="first", focus:auto=true)
+                        input(id="second")
+                    }
+                }
+            } else {
+                view! {}
+            })
+        }),
+        &test_container(),
+    );
+
+    show_modal.set(true);
+    // Focus is requested in a microtask after the node is mounted.
+    JsFuture::from(js_sys::Promise::resolve(&JsValue::UNDEFINED))
+        .await
+        .unwrap();
+
+    let first = document().query_selector("#first").unwrap().unwrap();
+    assert_eq!(document().active_element(), Some(first));
+}
+
+#[wasm_bindgen_test]
+fn select_on_focus() {
+    let node = view! {
+        input(value="hello", select_on_focus=true)
+    };
+
+    sycamore::render_to(|| node, &test_container());
+
+    let input = document()
+        .query_selector("input")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlInputElement>();
+    input.set_selection_range(0, 0).unwrap();
+
+    input.dispatch_event(&Event::new("focus").unwrap()).unwrap();
+    assert_eq!(input.selection_start().unwrap(), Some(0));
+    assert_eq!(input.selection_end().unwrap(), Some(5));
+}
+
+#[wasm_bindgen_test]
+fn boolean_attribute_toggles() {
+    let disabled = Signal::new(false);
+
+    let node = cloned!((disabled) => view! {
+        button(disabled=*disabled.get()) { "Submit" }
+    });
+
+    sycamore::render_to(|| node, &test_container());
+
+    let button = document().query_selector("button").unwrap().unwrap();
+    assert!(!button.has_attribute("disabled"));
+
+    disabled.set(true);
+    assert_eq!(button.get_attribute("disabled").as_deref(), Some(""));
+
+    disabled.set(false);
+    assert!(!button.has_attribute("disabled"));
+}
+
+#[wasm_bindgen_test]
+fn text_component_does_not_interpret_html() {
+    let value = Signal::new("<script>alert(1)</script>".to_string());
+
+    let node = cloned!((value) => view! {
+        p { sycamore::text::Text(value.handle()) }
+    });
+
+    sycamore::render_to(|| node, &test_container());
+
+    let p = document().query_selector("p").unwrap().unwrap();
+    assert_eq!(p.text_content().unwrap(), "<script>alert(1)</script>");
+    assert_eq!(p.child_element_count(), 0);
+
+    value.set("<b>bold</b>".to_string());
+    assert_eq!(p.text_content().unwrap(), "<b>bold</b>");
+    assert_eq!(p.child_element_count(), 0);
+}
+
+#[wasm_bindgen_test]
+fn classes_attribute() {
+    let classes = Signal::new(vec!["a".to_string(), "b".to_string()]);
+
+    let node = cloned!((classes) => view! {
+        div(class="static", classes=classes)
+    });
+
+    sycamore::render_to(|| node, &test_container());
+
+    let div = document().query_selector("div").unwrap().unwrap();
+    let class_list = div.class_list();
+    assert!(class_list.contains("static"));
+    assert!(class_list.contains("a"));
+    assert!(class_list.contains("b"));
+
+    classes.set(vec!["b".to_string(), "c".to_string()]);
+    assert!(class_list.contains("static"));
+    assert!(!class_list.contains("a"));
+    assert!(class_list.contains("b"));
+    assert!(class_list.contains("c"));
+    assert_eq!(class_list.length(), 3);
+}
+
+#[wasm_bindgen_test]
+fn class_toggles() {
+    let active = Signal::new(false);
+    let primary = Signal::new(true);
+
+    let node = cloned!((active, primary) => view! {
+        button(class=("btn", active => *active.get(), "btn-primary" => *primary.get()))
+    });
+
+    sycamore::render_to(|| node, &test_container());
+
+    let button = document().query_selector("button").unwrap().unwrap();
+    assert_eq!(button.class_name(), "btn btn-primary");
+
+    active.set(true);
+    assert_eq!(button.class_name(), "btn btn-primary active");
+
+    primary.set(false);
+    active.set(false);
+    assert_eq!(button.class_name(), "btn");
+}
+
+#[wasm_bindgen_test]
+fn style_properties() {
+    let progress = Signal::new(0.25);
+
+    let node = cloned!((progress) => view! {
+        div(style="color: red;", style:--progress=progress.get(), style:background-color="blue")
+    });
+
+    sycamore::render_to(|| node, &test_container());
+
+    let div = document().query_selector("div").unwrap().unwrap();
+    let style = div.unchecked_ref::<HtmlElement>().style();
+    assert_eq!(style.get_property_value("--progress").unwrap(), "0.25");
+    assert_eq!(
+        style.get_property_value("background-color").unwrap(),
+        "blue"
+    );
+    assert_eq!(style.get_property_value("color").unwrap(), "red");
+
+    progress.set(0.5);
+    assert_eq!(style.get_property_value("--progress").unwrap(), "0.5");
+}
+
 #[wasm_bindgen_test]
 fn noderefs() {
     let noderef = NodeRef::new();
@@ -380,6 +682,26 @@ fn fragments() {
     assert_eq!(test_container.text_content().unwrap(), "123");
 }
 
+#[wasm_bindgen_test]
+fn render_to_detached_fragment() {
+    let (fragment, _scope) = sycamore::render_to_fragment(|| {
+        view! {
+            p { "1" }
+            p { "2" }
+            p { "3" }
+        }
+    });
+
+    assert_eq!(fragment.child_element_count(), 3);
+    assert_eq!(fragment.text_content().unwrap(), "123");
+
+    let test_container = test_container();
+    test_container.append_child(&fragment).unwrap();
+
+    assert_eq!(fragment.child_element_count(), 0);
+    assert_eq!(test_container.text_content().unwrap(), "123");
+}
+
 #[wasm_bindgen_test]
 fn fragments_text_nodes() {
     let node = view! {
@@ -0,0 +1,72 @@
+use sycamore::motion::{TransitionGroup, TransitionGroupProps};
+
+use super::*;
+
+#[wasm_bindgen_test]
+async fn transition_group_animates_moved_items() {
+    let items = Signal::new(vec![1, 2, 3]);
+
+    let node = cloned!((items) => view! {
+        ul {
+            TransitionGroup(TransitionGroupProps {
+                iterable: items.handle(),
+                template: |item| view! {
+                    li(style="display: block; height: 20px;") { (item) }
+                },
+                key: |item| *item,
+                duration: 1000.0,
+            })
+        }
+    });
+
+    sycamore::render_to(|| node, &test_container());
+
+    let ul = document().query_selector("ul").unwrap().unwrap();
+    assert_eq!(ul.text_content().unwrap(), "123");
+
+    items.set(vec![3, 2, 1]);
+    assert_eq!(ul.text_content().unwrap(), "321");
+
+    // Animations are started in a microtask after the DOM has been updated.
+    sleep(0).await;
+
+    let first = document()
+        .query_selector("li")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    assert_eq!(first.text_content().unwrap(), "3");
+    assert!(first
+        .style()
+        .get_property_value("transition")
+        .unwrap()
+        .contains("transform"));
+}
+
+#[wasm_bindgen_test]
+async fn transition_group_fades_out_removed_items() {
+    let items = Signal::new(vec![1, 2]);
+
+    let node = cloned!((items) => view! {
+        ul(style="position: relative;") {
+            TransitionGroup(TransitionGroupProps {
+                iterable: items.handle(),
+                template: |item| view! { li { (item) } },
+                key: |item| *item,
+                duration: 50.0,
+            })
+        }
+    });
+
+    sycamore::render_to(|| node, &test_container());
+
+    let ul = document().query_selector("ul").unwrap().unwrap();
+
+    items.set(vec![1]);
+    sleep(0).await;
+    // The removed item is still visible while it is fading out.
+    assert_eq!(ul.child_element_count(), 2);
+
+    sleep(100).await;
+    assert_eq!(ul.text_content().unwrap(), "1");
+}
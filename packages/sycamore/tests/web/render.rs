@@ -1,3 +1,5 @@
+use sycamore::{DomBackend, WebBackend};
+
 use super::*;
 
 #[wasm_bindgen_test]
@@ -69,3 +71,97 @@ fn lazy_in_fragment() {
 
     assert_eq!(test_container.text_content().unwrap(), "before1after");
 }
+
+#[wasm_bindgen_test]
+fn render_to_shadow() {
+    let host = document().create_element("div").unwrap();
+    test_container().append_child(&host).unwrap();
+
+    let (shadow_root, handle) = sycamore::render_to_shadow(
+        || view! { p { "Shadow" } },
+        &host,
+        web_sys::ShadowRootMode::Open,
+    );
+
+    assert_eq!(host.shadow_root(), Some(shadow_root.clone()));
+    assert_eq!(shadow_root.text_content().unwrap(), "Shadow");
+    // The rendered nodes are not children of the host.
+    assert_eq!(host.child_element_count(), 0);
+    assert!(document().query_selector("p").unwrap().is_none());
+
+    handle.unmount();
+    assert_eq!(shadow_root.text_content().unwrap(), "");
+}
+
+/// A [`DomBackend`] that records the tag names of the elements it creates.
+struct RecordingBackend(Signal<Vec<String>>);
+
+impl DomBackend for RecordingBackend {
+    fn document(&self) -> Document {
+        document()
+    }
+
+    fn create_element(&self, tag: &str) -> web_sys::Element {
+        self.0.push(tag.to_string());
+        WebBackend.create_element(tag)
+    }
+}
+
+#[wasm_bindgen_test]
+fn render_with_backend() {
+    let created = Signal::new(Vec::new());
+    let show = Signal::new(false);
+
+    let _scope = sycamore::render_with_backend(
+        cloned!((show) => move || view! {
+            div {
+                p { "Hello" }
+                (if *show.get() { view! { span { "World" } } } else { view! {} })
+            }
+        }),
+        &test_container(),
+        RecordingBackend(created.clone()),
+    );
+    assert_eq!(*created.get(), ["div", "p"]);
+
+    // Nodes created when the view is updated also use the backend.
+    show.set(true);
+    assert_eq!(*created.get(), ["div", "p", "span"]);
+    assert_eq!(
+        document()
+            .query_selector("span")
+            .unwrap()
+            .unwrap()
+            .outer_html(),
+        "<span>World</span>"
+    );
+}
+
+#[wasm_bindgen_test]
+fn unmount_removes_nodes_and_cleans_up() {
+    let count = Signal::new(0);
+    let cleaned_up = Signal::new(false);
+
+    let container = test_container();
+    let handle = sycamore::render_to(
+        cloned!((count, cleaned_up) => move || {
+            on_cleanup(move || cleaned_up.set(true));
+            view! {
+                p { (count.get()) }
+                p { "items" }
+            }
+        }),
+        &container,
+    );
+    assert_eq!(container.text_content().unwrap(), "0items");
+    assert_eq!(count.subscriber_count(), 1);
+
+    handle.unmount();
+    assert!(!container.has_child_nodes());
+    assert_eq!(count.subscriber_count(), 0);
+    assert!(*cleaned_up.get());
+
+    // The view is no longer updated.
+    count.set(1);
+    assert_eq!(container.text_content().unwrap(), "");
+}
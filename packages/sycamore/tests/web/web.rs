@@ -0,0 +1,533 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use sycamore::style::scoped_css;
+use sycamore::web::{
+    create_delayed_loading, create_signal_from_event, create_throttled_effect, custom_event_detail,
+    dispatch_custom_event, read_then_write, use_color_scheme, use_draggable, use_draggable_with,
+    use_element_size, use_hotkey, use_intersection, use_interval, use_media_query, use_mouse,
+    use_mouse_relative_to, use_online, use_page_visible, use_timeout, use_title, use_window_scroll,
+    ColorScheme, DragAxis, DraggableOptions,
+};
+
+use super::*;
+
+#[wasm_bindgen_test]
+fn media_query() {
+    let _ = create_root(|| {
+        assert!(*use_media_query("all").get());
+        assert!(!*use_media_query("not all").get());
+    });
+}
+
+#[wasm_bindgen_test]
+fn online() {
+    let online = Signal::new(None);
+    let scope = create_root(cloned!((online) => move || online.set(Some(use_online()))));
+    let online = online.get().as_ref().clone().unwrap();
+    assert_eq!(*online.get(), window().navigator().on_line());
+
+    window()
+        .dispatch_event(&Event::new("offline").unwrap())
+        .unwrap();
+    assert!(!*online.get());
+    window()
+        .dispatch_event(&Event::new("online").unwrap())
+        .unwrap();
+    assert!(*online.get());
+
+    // The listeners are removed when the scope is dropped.
+    drop(scope);
+    window()
+        .dispatch_event(&Event::new("offline").unwrap())
+        .unwrap();
+    assert!(*online.get());
+}
+
+#[wasm_bindgen_test]
+fn color_scheme() {
+    let storage = window().local_storage().unwrap().unwrap();
+    storage.remove_item("sycamore-color-scheme").unwrap();
+    let prefers_dark = window()
+        .match_media("(prefers-color-scheme: dark)")
+        .unwrap()
+        .unwrap()
+        .matches();
+
+    let _ = create_root(|| {
+        let (scheme, set_scheme) = use_color_scheme();
+        let system = if prefers_dark {
+            ColorScheme::Dark
+        } else {
+            ColorScheme::Light
+        };
+        assert_eq!(*scheme.get(), system);
+
+        set_scheme(ColorScheme::Dark);
+        assert_eq!(*scheme.get(), ColorScheme::Dark);
+    });
+
+    // The choice is restored.
+    assert_eq!(
+        storage
+            .get_item("sycamore-color-scheme")
+            .unwrap()
+            .as_deref(),
+        Some("dark")
+    );
+    let _ = create_root(|| assert_eq!(*use_color_scheme().0.get(), ColorScheme::Dark));
+    storage.remove_item("sycamore-color-scheme").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn page_visible() {
+    /// Overrides `document.hidden` and fires `visibilitychange`.
+    fn set_hidden(hidden: bool) {
+        let descriptor = js_sys::Object::new();
+        js_sys::Reflect::set(&descriptor, &"value".into(), &hidden.into()).unwrap();
+        js_sys::Reflect::set(&descriptor, &"configurable".into(), &true.into()).unwrap();
+        js_sys::Object::define_property(&document(), &"hidden".into(), &descriptor);
+        document()
+            .dispatch_event(&Event::new("visibilitychange").unwrap())
+            .unwrap();
+    }
+
+    let visible = Signal::new(None);
+    let scope = create_root(cloned!((visible) => move || visible.set(Some(use_page_visible()))));
+    let visible = visible.get().as_ref().clone().unwrap();
+    assert_eq!(*visible.get(), !document().hidden());
+
+    set_hidden(true);
+    assert!(!*visible.get());
+    set_hidden(false);
+    assert!(*visible.get());
+
+    // The listener is removed when the scope is dropped.
+    drop(scope);
+    set_hidden(true);
+    assert!(*visible.get());
+    js_sys::Reflect::delete_property(&document(), &"hidden".into()).unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn window_scroll() {
+    let spacer = document().create_element("div").unwrap();
+    spacer.set_attribute("style", "height: 10000px;").unwrap();
+    test_container().append_child(&spacer).unwrap();
+
+    let position = Signal::new(None);
+    let scope = create_root(cloned!((position) => move || position.set(Some(use_window_scroll()))));
+    let position = position.get().as_ref().clone().unwrap();
+    assert_eq!(*position.get(), (0.0, 0.0));
+
+    window().scroll_to_with_x_and_y(0.0, 500.0);
+    sleep(100).await;
+    assert_eq!(*position.get(), (0.0, 500.0));
+
+    drop(scope);
+    window().scroll_to_with_x_and_y(0.0, 0.0);
+    test_container().remove_child(&spacer).unwrap();
+}
+
+#[wasm_bindgen_test]
+fn signal_from_event() {
+    let target = document().create_element("div").unwrap();
+    let count = Signal::new(None);
+
+    let scope = create_root(cloned!((target, count) => move || {
+        let n = std::cell::Cell::new(0);
+        count.set(Some(create_signal_from_event(
+            &target,
+            "ping",
+            move |_| {
+                n.set(n.get() + 1);
+                n.get()
+            },
+            0,
+        )));
+    }));
+    let count = count.get().as_ref().clone().unwrap();
+    assert_eq!(*count.get(), 0);
+
+    target.dispatch_event(&Event::new("ping").unwrap()).unwrap();
+    target.dispatch_event(&Event::new("ping").unwrap()).unwrap();
+    assert_eq!(*count.get(), 2);
+
+    // The listener is removed when the scope is dropped.
+    drop(scope);
+    target.dispatch_event(&Event::new("ping").unwrap()).unwrap();
+    assert_eq!(*count.get(), 2);
+}
+
+#[wasm_bindgen_test]
+fn title() {
+    let page = Signal::new("Home".to_string());
+
+    let scope = create_root(cloned!((page) => move || use_title(page.handle())));
+    assert_eq!(document().title(), "Home");
+
+    page.set("About".to_string());
+    assert_eq!(document().title(), "About");
+
+    drop(scope);
+    page.set("Contact".to_string());
+    assert_eq!(document().title(), "About");
+}
+
+#[wasm_bindgen_test]
+fn custom_event_bubbles_to_ancestor() {
+    let selected = Signal::new(None);
+
+    let node = cloned!((selected) => view! {
+        ul(on:item-selected=move |event| {
+            selected.set(custom_event_detail(&event).as_f64());
+        }) {
+            li { "Item" }
+        }
+    });
+    sycamore::render_to(|| node, &test_container());
+
+    let item = document().query_selector("li").unwrap().unwrap();
+    assert!(dispatch_custom_event(&item, "item-selected", 7));
+    assert_eq!(*selected.get(), Some(7.0));
+
+    // Not a custom event, so there is no detail.
+    item.dispatch_event(&Event::new("item-selected").unwrap())
+        .unwrap();
+    assert_eq!(*selected.get(), None);
+}
+
+/// Dispatches a cancelable `keydown` event on the window and returns whether it was cancelled.
+fn press(key: &str, ctrl: bool) -> bool {
+    let init = js_sys::Object::new();
+    js_sys::Reflect::set(&init, &"key".into(), &key.into()).unwrap();
+    js_sys::Reflect::set(&init, &"ctrlKey".into(), &ctrl.into()).unwrap();
+    js_sys::Reflect::set(&init, &"cancelable".into(), &true.into()).unwrap();
+    let constructor = js_sys::Reflect::get(&window(), &"KeyboardEvent".into()).unwrap();
+    let event: Event = js_sys::Reflect::construct(
+        constructor.unchecked_ref::<js_sys::Function>(),
+        &js_sys::Array::of2(&"keydown".into(), &init),
+    )
+    .unwrap()
+    .unchecked_into();
+    !window().dispatch_event(&event).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn hotkey() {
+    let opened = Rc::new(Cell::new(0));
+    let closed = Rc::new(Cell::new(0));
+
+    let scope = create_root(cloned!((opened, closed) => move || {
+        use_hotkey("ctrl+k", move || opened.set(opened.get() + 1));
+        use_hotkey("esc", move || closed.set(closed.get() + 1));
+    }));
+
+    assert!(press("k", true));
+    assert!(press("K", true));
+    assert_eq!(opened.get(), 2);
+
+    // Modifiers must match exactly.
+    assert!(!press("k", false));
+    assert!(!press("Escape", true));
+    assert_eq!(opened.get(), 2);
+    assert_eq!(closed.get(), 0);
+
+    assert!(press("Escape", false));
+    assert_eq!(closed.get(), 1);
+
+    // The listeners are removed when the scope is dropped.
+    drop(scope);
+    assert!(!press("k", true));
+    assert_eq!(opened.get(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn interval() {
+    let ticks = Rc::new(Cell::new(0));
+    let scope = create_root(cloned!((ticks) => move || {
+        use_interval(10, move || ticks.set(ticks.get() + 1));
+    }));
+
+    sleep(55).await;
+    assert!(ticks.get() >= 2);
+
+    // The interval is cleared when the scope is dropped.
+    drop(scope);
+    let after_drop = ticks.get();
+    sleep(30).await;
+    assert_eq!(ticks.get(), after_drop);
+}
+
+#[wasm_bindgen_test]
+async fn timeout() {
+    let fired = Rc::new(Cell::new(false));
+    let _scope = create_root(cloned!((fired) => move || use_timeout(10, move || fired.set(true))));
+    assert!(!fired.get());
+    sleep(30).await;
+    assert!(fired.get());
+
+    // A timeout is not called once its scope has been dropped.
+    let cancelled = Rc::new(Cell::new(false));
+    let scope = create_root(cloned!((cancelled) => move || {
+        use_timeout(10, move || cancelled.set(true));
+    }));
+    drop(scope);
+    sleep(30).await;
+    assert!(!cancelled.get());
+}
+
+#[wasm_bindgen_test]
+async fn delayed_loading() {
+    let is_loading = Signal::new(false);
+    let shown = Signal::new(None);
+    let _scope = create_root(cloned!((is_loading, shown) => move || {
+        shown.set(Some(create_delayed_loading(is_loading.handle(), 20, 100)));
+    }));
+    let shown = shown.get().as_ref().clone().unwrap();
+
+    // A load that finishes before the delay never shows the indicator.
+    is_loading.set(true);
+    sleep(5).await;
+    is_loading.set(false);
+    sleep(40).await;
+    assert!(!*shown.get());
+
+    // Once shown, the indicator stays visible for the minimum duration.
+    is_loading.set(true);
+    sleep(40).await;
+    assert!(*shown.get());
+    is_loading.set(false);
+    sleep(30).await;
+    assert!(*shown.get());
+    sleep(120).await;
+    assert!(!*shown.get());
+}
+
+#[wasm_bindgen_test]
+async fn throttled_effect() {
+    let state = Signal::new(0);
+    let runs = Signal::new(Vec::new());
+    let scope = create_root(cloned!((state, runs) => move || {
+        create_throttled_effect(50, move || runs.push(*state.get()));
+    }));
+    assert_eq!(*runs.get(), vec![0]);
+
+    // Changes within the interval result in a single trailing run with the final value.
+    state.set(1);
+    state.set(2);
+    assert_eq!(*runs.get(), vec![0]);
+    sleep(80).await;
+    assert_eq!(*runs.get(), vec![0, 2]);
+
+    // Dependencies are still tracked after the trailing run.
+    sleep(60).await;
+    state.set(3);
+    assert_eq!(*runs.get(), vec![0, 2, 3]);
+
+    // A pending trailing run is cancelled when the scope is dropped.
+    state.set(4);
+    drop(scope);
+    sleep(80).await;
+    assert_eq!(*runs.get(), vec![0, 2, 3]);
+}
+
+#[wasm_bindgen_test]
+async fn reads_run_before_writes() {
+    let log = Signal::new(Vec::new());
+
+    for i in 0..2 {
+        read_then_write(
+            cloned!((log) => move || {
+                log.push(format!("read {}", i));
+                i * 10
+            }),
+            cloned!((log) => move |value| log.push(format!("write {}", value))),
+        );
+    }
+    assert!(log.get().is_empty());
+
+    sleep(50).await;
+    assert_eq!(*log.get(), vec!["read 0", "read 1", "write 0", "write 10"]);
+}
+
+#[wasm_bindgen_test]
+async fn intersection() {
+    let visible = Signal::new(None);
+    let hidden = Signal::new(None);
+
+    sycamore::render_to(
+        cloned!((visible, hidden) => move || {
+            let visible_ref = NodeRef::new();
+            let hidden_ref = NodeRef::new();
+            visible.set(Some(use_intersection(visible_ref.clone())));
+            hidden.set(Some(use_intersection(hidden_ref.clone())));
+            view! {
+                div(ref=visible_ref) { "Visible" }
+                div(ref=hidden_ref, style="position: fixed; top: -1000px;") { "Hidden" }
+            }
+        }),
+        &test_container(),
+    );
+
+    sleep(100).await;
+    assert!(*visible.get().as_ref().as_ref().unwrap().get());
+    assert!(!*hidden.get().as_ref().as_ref().unwrap().get());
+}
+
+#[wasm_bindgen_test]
+async fn element_size() {
+    let size = Signal::new(None);
+
+    sycamore::render_to(
+        cloned!((size) => move || {
+            let node = NodeRef::new();
+            let element_size = use_element_size(node.clone());
+            assert_eq!(*element_size.get(), (0.0, 0.0));
+            size.set(Some(element_size));
+            view! {
+                div(ref=node, style="width: 120px; height: 80px;")
+            }
+        }),
+        &test_container(),
+    );
+
+    sleep(100).await;
+    assert_eq!(*size.get().as_ref().as_ref().unwrap().get(), (120.0, 80.0));
+}
+
+#[wasm_bindgen_test]
+async fn mouse() {
+    let positions = Signal::new(None);
+    let scope = create_root(cloned!((positions) => move || {
+        let node = NodeRef::new();
+        positions.set(Some((use_mouse(), use_mouse_relative_to(node.clone()))));
+        sycamore::render_to(
+            || view! {
+                div(ref=node, style="position: fixed; left: 10px; top: 20px;")
+            },
+            &test_container(),
+        );
+    }));
+    let (mouse, relative) = positions.get().as_ref().clone().unwrap();
+    assert_eq!(*mouse.get(), (0.0, 0.0));
+
+    let init = js_sys::Object::new();
+    js_sys::Reflect::set(&init, &"clientX".into(), &50.into()).unwrap();
+    js_sys::Reflect::set(&init, &"clientY".into(), &60.into()).unwrap();
+    let constructor = js_sys::Reflect::get(&window(), &"MouseEvent".into()).unwrap();
+    let event: Event = js_sys::Reflect::construct(
+        constructor.unchecked_ref::<js_sys::Function>(),
+        &js_sys::Array::of2(&"mousemove".into(), &init),
+    )
+    .unwrap()
+    .unchecked_into();
+    window().dispatch_event(&event).unwrap();
+    // The position is updated in the next animation frame.
+    assert_eq!(*mouse.get(), (0.0, 0.0));
+    sleep(50).await;
+    assert_eq!(*mouse.get(), (50.0, 60.0));
+    assert_eq!(*relative.get(), (40.0, 40.0));
+
+    // The listener is removed when the scope is dropped.
+    drop(scope);
+    window().dispatch_event(&event).unwrap();
+    sleep(50).await;
+    assert_eq!(*mouse.get(), (50.0, 60.0));
+}
+
+/// Dispatches a pointer event of type `ty` at `(x, y)` on `target`.
+fn pointer(target: &web_sys::Element, ty: &str, x: i32, y: i32) {
+    let init = js_sys::Object::new();
+    js_sys::Reflect::set(&init, &"clientX".into(), &x.into()).unwrap();
+    js_sys::Reflect::set(&init, &"clientY".into(), &y.into()).unwrap();
+    js_sys::Reflect::set(&init, &"pointerId".into(), &1.into()).unwrap();
+    let constructor = js_sys::Reflect::get(&window(), &"PointerEvent".into()).unwrap();
+    let event: Event = js_sys::Reflect::construct(
+        constructor.unchecked_ref::<js_sys::Function>(),
+        &js_sys::Array::of2(&ty.into(), &init),
+    )
+    .unwrap()
+    .unchecked_into();
+    target.dispatch_event(&event).unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn draggable() {
+    let offsets = Signal::new(None);
+
+    let scope = create_root(cloned!((offsets) => move || {
+        let free = NodeRef::new();
+        let horizontal = NodeRef::new();
+        offsets.set(Some((
+            use_draggable(free.clone()),
+            use_draggable_with(horizontal.clone(), DraggableOptions { axis: DragAxis::X }),
+        )));
+        sycamore::render_to(
+            || view! {
+                div(ref=free, id="free")
+                div(ref=horizontal, id="horizontal")
+            },
+            &test_container(),
+        );
+    }));
+    let (free, horizontal) = offsets.get().as_ref().clone().unwrap();
+    sleep(0).await;
+
+    let free_el = document().get_element_by_id("free").unwrap();
+    pointer(&free_el, "pointermove", 50, 50); // Not dragging yet.
+    pointer(&free_el, "pointerdown", 10, 10);
+    pointer(&free_el, "pointermove", 30, 15);
+    pointer(&free_el, "pointerup", 30, 15);
+    assert_eq!(*free.get(), (20.0, 5.0));
+
+    // The next drag continues from the current offset.
+    pointer(&free_el, "pointerdown", 0, 0);
+    pointer(&free_el, "pointermove", 5, 5);
+    pointer(&free_el, "pointerup", 5, 5);
+    assert_eq!(*free.get(), (25.0, 10.0));
+
+    let horizontal_el = document().get_element_by_id("horizontal").unwrap();
+    pointer(&horizontal_el, "pointerdown", 0, 0);
+    pointer(&horizontal_el, "pointermove", 40, 40);
+    assert_eq!(*horizontal.get(), (40.0, 0.0));
+
+    // The listeners are removed when the scope is dropped.
+    drop(scope);
+    pointer(&horizontal_el, "pointermove", 80, 80);
+    assert_eq!(*horizontal.get(), (40.0, 0.0));
+}
+
+#[wasm_bindgen_test]
+fn scoped_css_injects_stylesheet_once() {
+    let css = "& { color: rgb(255, 0, 0); }";
+
+    sycamore::render_to(
+        move || {
+            let class = scoped_css(css);
+            let class2 = scoped_css(css);
+            assert_eq!(class, class2);
+            view! {
+                p(class=class) { "Red" }
+                p(class=class2) { "Also red" }
+            }
+        },
+        &test_container(),
+    );
+
+    let class = document()
+        .query_selector("p")
+        .unwrap()
+        .unwrap()
+        .class_name();
+    let head = document().head().unwrap().children();
+    let styles = (0..head.length())
+        .filter_map(|i| head.item(i))
+        .filter(|el| el.get_attribute("data-sycamore-css").as_deref() == Some(class.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(styles.len(), 1);
+    assert_eq!(
+        styles[0].text_content().unwrap(),
+        format!(".{} {{ color: rgb(255, 0, 0); }}", class)
+    );
+}